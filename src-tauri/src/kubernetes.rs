@@ -1,4 +1,6 @@
 pub mod client {
+    use super::kubeconfig_watch::watch_kubeconfigs;
+    use super::rollout::track_rollout_if_requested;
     use either::Either;
     use k8s_metrics::v1beta1::PodMetrics;
     use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
@@ -8,15 +10,27 @@ pub mod client {
     };
     use k8s_openapi::api::networking::v1::Ingress;
     use k8s_openapi::apimachinery::pkg::apis::meta::v1::{APIGroup, APIResource};
-    use kube::api::{DeleteParams, ListParams, ObjectMeta, PostParams};
+    use kube::api::{DeleteParams, ListParams, ObjectList, ObjectMeta, Patch, PatchParams, PostParams};
     use kube::config::{KubeConfigOptions, Kubeconfig, KubeconfigError, NamedAuthInfo, NamedContext};
+    use kube::client::AuthError;
+    use kube::error::DiscoveryError;
     use kube::{api::Api, Client, Config, Error};
     use rand::distributions::DistString;
-    use serde::Serialize;
-    use std::sync::Mutex;
+    use serde::de::DeserializeOwned;
+    use serde::{Deserialize, Serialize};
+    use serde_json::{Map, Value};
+    use std::collections::HashMap;
+    use std::error::Error as StdError;
+    use std::future::Future;
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::{env, fs};
+    use std::time::{Duration, Instant};
     use tracing::{debug, error, info, trace, warn};
     use tokio::process::Command;
-    use std::io;
+    use tokio::sync::{watch, Mutex as AsyncMutex};
+    use tokio::time::timeout;
 
 
     #[derive(Serialize)]
@@ -25,28 +39,164 @@ pub mod client {
         Pending(String),
     }
 
+    /// Broad category of a failure, so the frontend can pick a message and decide whether
+    /// retrying makes sense without parsing error strings.
+    #[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+    pub enum ErrorKind {
+        Network,
+        Tls,
+        Auth,
+        Forbidden,
+        NotFound,
+        /// A continue token or resource version the server no longer has; the list has to
+        /// be started over.
+        Expired,
+        Conflict,
+        Invalid,
+        TooManyRequests,
+        Timeout,
+        Cancelled,
+        ServerError,
+        MetricsUnavailable,
+        Kubeconfig,
+        ExecPlugin,
+        Unsupported,
+        Unknown,
+    }
+
+    // Fragments of rustls, openssl and hyper messages for failed certificate checks
+    const TLS_MARKERS: [&str; 5] = ["certificate", "x509", "tls", "handshake", "ssl"];
+
+    // Connection errors often only say what went wrong in their sources, which is why the
+    // whole chain is searched
+    fn error_chain(error: &Error) -> String {
+        let mut messages = vec![error.to_string()];
+        messages.extend(error_causes(error));
+        messages.join(": ").to_lowercase()
+    }
+
+    fn mentions_tls(chain: &str) -> bool {
+        TLS_MARKERS.iter().any(|marker| chain.contains(marker))
+    }
+
+    impl ErrorKind {
+        pub(crate) fn from_status(code: u16) -> Self {
+            match code {
+                401 => ErrorKind::Auth,
+                403 => ErrorKind::Forbidden,
+                404 => ErrorKind::NotFound,
+                410 => ErrorKind::Expired,
+                409 => ErrorKind::Conflict,
+                400 | 422 => ErrorKind::Invalid,
+                429 => ErrorKind::TooManyRequests,
+                408 | 504 => ErrorKind::Timeout,
+                500..=599 => ErrorKind::ServerError,
+                _ => ErrorKind::Unknown,
+            }
+        }
+
+        pub(crate) fn of(error: &Error) -> Self {
+            match error {
+                Error::Api(response) => ErrorKind::from_status(response.code),
+                Error::Auth(
+                    AuthError::ExecPluginFailed
+                    | AuthError::AuthExecStart(_)
+                    | AuthError::AuthExecRun { .. }
+                    | AuthError::AuthExecParse(_)
+                    | AuthError::AuthExec(_)
+                    | AuthError::MissingCommand,
+                ) => ErrorKind::ExecPlugin,
+                Error::Auth(_) => ErrorKind::Auth,
+                Error::InferConfig(_) => ErrorKind::Kubeconfig,
+                Error::Discovery(DiscoveryError::InvalidGroupVersion(_)) => ErrorKind::Invalid,
+                // The cluster does not serve the API group, version or kind that was asked for
+                Error::Discovery(_) => ErrorKind::Unsupported,
+                Error::RustlsTls(_) | Error::TlsRequired => ErrorKind::Tls,
+                Error::HyperError(_) | Error::Service(_) | Error::ReadEvents(_) => {
+                    let chain = error_chain(error);
+                    if mentions_tls(&chain) {
+                        ErrorKind::Tls
+                    } else if chain.contains("timed out") || chain.contains("deadline has elapsed") {
+                        ErrorKind::Timeout
+                    } else {
+                        ErrorKind::Network
+                    }
+                }
+                _ if mentions_tls(&error_chain(error)) => ErrorKind::Tls,
+                _ => ErrorKind::Unknown,
+            }
+        }
+
+        /// Failures that may succeed when the same request is sent again.
+        pub(crate) fn is_retryable(self) -> bool {
+            matches!(
+                self,
+                ErrorKind::Network | ErrorKind::Timeout | ErrorKind::TooManyRequests | ErrorKind::ServerError
+            )
+        }
+    }
+
+    /// The messages of an error's sources, outermost first, leaving out sources whose
+    /// message is already part of the one before.
+    pub(crate) fn error_causes(error: &dyn StdError) -> Vec<String> {
+        let mut causes: Vec<String> = Vec::new();
+        let mut source = error.source();
+        let mut previous = error.to_string();
+        while let Some(cause) = source {
+            let message = cause.to_string();
+            if !previous.contains(&message) {
+                causes.push(message.clone());
+            }
+            previous = message;
+            source = cause.source();
+        }
+        causes
+    }
+
     #[derive(Debug, Serialize)]
     pub struct SerializableKubeError {
-        message: String,
-        code: Option<u16>,
-        reason: Option<String>,
-        details: Option<String>,
+        pub(crate) message: String,
+        pub(crate) code: Option<u16>,
+        pub(crate) reason: Option<String>,
+        pub(crate) details: Option<serde_json::Value>,
+        pub(crate) kind: ErrorKind,
+        pub(crate) causes: Vec<String>,
+    }
+
+    impl SerializableKubeError {
+        /// An error that did not come from the API server.
+        pub(crate) fn new(kind: ErrorKind, reason: &str, message: String) -> Self {
+            SerializableKubeError {
+                message,
+                code: None,
+                reason: Some(reason.to_string()),
+                details: None,
+                kind,
+                causes: Vec::new(),
+            }
+        }
     }
 
     impl From<Error> for SerializableKubeError {
         fn from(error: Error) -> Self {
             error!("Kubernetes API error occurred: {:?}", error);
+            let kind = ErrorKind::of(&error);
+            let causes = error_causes(&error);
 
             match error {
                 Error::Api(api_error) => {
                     let code = api_error.code;
                     let reason = api_error.reason;
                     let message = api_error.message;
+                    let details = parse_apply_conflicts(&message)
+                        .map(|conflicts| serde_json::json!({ "conflicts": conflicts }));
                     return SerializableKubeError {
                         message,
                         code: Option::from(code),
                         reason: Option::from(reason),
-                        details: None,
+                        details,
+                        kind,
+                        causes,
                     };
                 }
                 _ => {
@@ -55,6 +205,8 @@ pub mod client {
                         code: None,
                         reason: None,
                         details: None,
+                        kind,
+                        causes,
                     };
                 }
             }
@@ -70,57 +222,343 @@ pub mod client {
                 code: None,
                 reason: None,
                 details: None,
+                kind: ErrorKind::Kubeconfig,
+                causes: error_causes(&error),
             };
         }
     }
 
-    static CURRENT_CONTEXT: Mutex<Option<String>> = Mutex::new(Some(String::new()));
-    static CURRENT_KUBECONFIG: Mutex<Option<String>> = Mutex::new(None);
-    static CLIENT: Mutex<Option<Client>> = Mutex::new(None);
+    // A missing or unhealthy metrics-server surfaces as the metrics API itself being absent
+    // (404 for the whole resource) or unavailable (503).
+    fn metrics_error(mut error: SerializableKubeError) -> SerializableKubeError {
+        let api_missing = error.code == Some(404)
+            && error.message == "the server could not find the requested resource";
+        if api_missing || error.code == Some(503) {
+            error.kind = ErrorKind::MetricsUnavailable;
+        }
+        error
+    }
+
+    #[derive(Debug, Serialize)]
+    pub struct FieldManagerConflict {
+        manager: String,
+        api_version: Option<String>,
+        field: String,
+    }
+
+    // Splits `"kubectl-edit" using apps/v1` into the manager and the API version it used
+    fn parse_conflicting_manager(manager: &str) -> (String, Option<String>) {
+        let (name, api_version) = match manager.split_once(" using ") {
+            Some((name, api_version)) => (name, Some(api_version.to_string())),
+            None => (manager, None),
+        };
+
+        (name.trim_matches('"').to_string(), api_version)
+    }
+
+    /// Extracts the conflicting field managers from the message of a rejected server-side apply,
+    /// which is either `Apply failed with 1 conflict: conflict with "manager" using v1: .field`
+    /// or lists every manager as `conflicts with "manager" using v1:` followed by `- .field` lines.
+    fn parse_apply_conflicts(message: &str) -> Option<Vec<FieldManagerConflict>> {
+        if !message.starts_with("Apply failed with ") {
+            return None;
+        }
+
+        let (_, conflicts) = message.split_once(": ")?;
+        let mut parsed = Vec::new();
+        let mut current_manager: Option<(String, Option<String>)> = None;
+
+        for line in conflicts.lines().map(str::trim) {
+            if let Some(rest) = line.strip_prefix("conflict with ") {
+                if let Some((manager, field)) = rest.rsplit_once(": ") {
+                    let (manager, api_version) = parse_conflicting_manager(manager);
+                    parsed.push(FieldManagerConflict {
+                        manager,
+                        api_version,
+                        field: field.to_string(),
+                    });
+                }
+            } else if let Some(rest) = line.strip_prefix("conflicts with ") {
+                current_manager = Some(parse_conflicting_manager(rest.trim_end_matches(':')));
+            } else if let (Some(field), Some((manager, api_version))) =
+                (line.strip_prefix("- "), current_manager.as_ref())
+            {
+                parsed.push(FieldManagerConflict {
+                    manager: manager.clone(),
+                    api_version: api_version.clone(),
+                    field: field.to_string(),
+                });
+            }
+        }
+
+        Some(parsed)
+    }
+
+    /// Field manager that owns every field JET Pilot writes through server-side apply
+    pub(crate) const FIELD_MANAGER: &str = "jet-pilot";
+
+    pub(crate) fn apply_params(force: bool) -> PatchParams {
+        let params = PatchParams::apply(FIELD_MANAGER);
+        if force {
+            params.force()
+        } else {
+            params
+        }
+    }
+
+    /// Drops the metadata the API server refuses or tracks by itself on server-side apply.
+    pub(crate) fn prepare_for_apply(metadata: &mut ObjectMeta) {
+        metadata.managed_fields = None;
+        metadata.resource_version = None;
+    }
+
+    /// Where contexts are read from. Multiple files are merged the way kubectl merges
+    /// `KUBECONFIG`: the first file to define a context, cluster, user or the current
+    /// context wins.
+    #[derive(Clone, Debug, Deserialize)]
+    #[serde(tag = "mode")]
+    pub enum KubeconfigSource {
+        /// `KUBECONFIG` when it is set, otherwise `~/.kube/config`.
+        Default,
+        /// The given files in order. Entries may themselves be `KUBECONFIG`-style lists.
+        Paths { paths: Vec<String> },
+        /// Every file in the directory, in file name order.
+        Directory { path: String },
+    }
+
+    struct ActiveKubeconfigs {
+        source: KubeconfigSource,
+        /// The files of the source that could be read, resolved when the source was last loaded.
+        files: Vec<String>,
+    }
+
+    static ACTIVE_KUBECONFIGS: Mutex<Option<ActiveKubeconfigs>> = Mutex::new(None);
+
+    /// A context annotated with the kubeconfig file that defined it.
+    #[derive(Clone, Debug, Serialize)]
+    pub struct SourcedContext {
+        #[serde(flatten)]
+        context: NamedContext,
+        source: String,
+    }
+
+    pub(crate) fn default_kubeconfig_paths() -> Vec<PathBuf> {
+        if let Some(value) = env::var_os("KUBECONFIG") {
+            let paths: Vec<PathBuf> = env::split_paths(&value)
+                .filter(|path| !path.as_os_str().is_empty())
+                .collect();
+            if !paths.is_empty() {
+                return paths;
+            }
+        }
+
+        env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .map(|home| vec![PathBuf::from(home).join(".kube").join("config")])
+            .unwrap_or_default()
+    }
+
+    /// Resolves a source to the files it merges, in precedence order. Like kubectl, files
+    /// that do not exist are skipped and a file listed twice only counts once.
+    fn kubeconfig_files(source: &KubeconfigSource) -> Result<Vec<PathBuf>, SerializableKubeError> {
+        let candidates = match source {
+            KubeconfigSource::Default => default_kubeconfig_paths(),
+            KubeconfigSource::Paths { paths } => paths
+                .iter()
+                .flat_map(env::split_paths)
+                .filter(|path| !path.as_os_str().is_empty())
+                .collect(),
+            KubeconfigSource::Directory { path } => {
+                let entries = fs::read_dir(path).map_err(|err| {
+                    error!("Failed to read kubeconfig directory {}: {}", path, err);
+                    SerializableKubeError::new(
+                        ErrorKind::Kubeconfig,
+                        "KubeconfigDirectoryUnreadable",
+                        format!("Failed to read kubeconfig directory {}: {}", path, err),
+                    )
+                })?;
+
+                let mut files: Vec<PathBuf> = entries
+                    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                    .filter(|path| path.is_file())
+                    .filter(|path| {
+                        !path
+                            .file_name()
+                            .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+                    })
+                    .collect();
+                files.sort();
+                files
+            }
+        };
+
+        let mut files: Vec<PathBuf> = Vec::new();
+        for candidate in candidates {
+            if candidate.is_file() && !files.contains(&candidate) {
+                files.push(candidate);
+            }
+        }
+
+        if files.is_empty() {
+            return Err(SerializableKubeError::new(
+                ErrorKind::Kubeconfig,
+                "NoKubeconfig",
+                "No kubeconfig files found".to_string(),
+            ));
+        }
+        Ok(files)
+    }
+
+    /// Reads every file of a source. Directories often hold unrelated files, so files that
+    /// fail to parse are skipped there, while explicitly listed files must be valid.
+    fn read_kubeconfig_files(
+        source: &KubeconfigSource,
+        files: &[PathBuf],
+    ) -> Result<Vec<(PathBuf, Kubeconfig)>, SerializableKubeError> {
+        let mut configs = Vec::with_capacity(files.len());
+        for file in files {
+            match Kubeconfig::read_from(file) {
+                Ok(config) => configs.push((file.clone(), config)),
+                Err(err) if matches!(source, KubeconfigSource::Directory { .. }) => {
+                    warn!("Skipping {} while scanning for kubeconfigs: {}", file.display(), err);
+                }
+                Err(err) => {
+                    error!("Failed to read kubeconfig {}: {}", file.display(), err);
+                    return Err(SerializableKubeError::from(err));
+                }
+            }
+        }
+        Ok(configs)
+    }
+
+    pub(crate) fn merge_kubeconfigs(configs: &[(PathBuf, Kubeconfig)]) -> Result<Kubeconfig, SerializableKubeError> {
+        configs
+            .iter()
+            .try_fold(Kubeconfig::default(), |merged, (_, config)| merged.merge(config.clone()))
+            .map_err(|err| {
+                error!("Failed to merge kubeconfigs: {}", err);
+                SerializableKubeError::from(err)
+            })
+    }
+
+    pub(crate) fn current_kubeconfig_source() -> KubeconfigSource {
+        ACTIVE_KUBECONFIGS
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|active| active.source.clone())
+            .unwrap_or(KubeconfigSource::Default)
+    }
+
+    fn activate_kubeconfigs(source: KubeconfigSource, configs: &[(PathBuf, Kubeconfig)]) {
+        let files = configs
+            .iter()
+            .map(|(file, _)| file.to_string_lossy().into_owned())
+            .collect();
+
+        ACTIVE_KUBECONFIGS
+            .lock()
+            .unwrap()
+            .replace(ActiveKubeconfigs { source, files });
+    }
+
+    /// Re-reads the active source, picking up files added to or removed from it.
+    pub(crate) fn reload_kubeconfigs() -> Result<Vec<(PathBuf, Kubeconfig)>, SerializableKubeError> {
+        let source = current_kubeconfig_source();
+        let files = kubeconfig_files(&source)?;
+        let configs = read_kubeconfig_files(&source, &files)?;
+        activate_kubeconfigs(source, &configs);
+        Ok(configs)
+    }
+
+    /// The files of the active source as strings, which is how clients are keyed. Before a
+    /// source was activated these are the files of the default source.
+    pub(crate) fn current_kubeconfig_files() -> Result<Vec<String>, SerializableKubeError> {
+        if let Some(active) = ACTIVE_KUBECONFIGS.lock().unwrap().as_ref() {
+            return Ok(active.files.clone());
+        }
+
+        Ok(kubeconfig_files(&KubeconfigSource::Default)?
+            .into_iter()
+            .map(|file| file.to_string_lossy().into_owned())
+            .collect())
+    }
+
+    /// Reads the files of the active source without changing which files are active, that is
+    /// left to switching sources and to the kubeconfig watcher. Files removed since they were
+    /// activated are skipped the same way missing files are when resolving a source.
+    pub(crate) fn read_kubeconfigs() -> Result<Vec<(PathBuf, Kubeconfig)>, SerializableKubeError> {
+        let files: Vec<PathBuf> = current_kubeconfig_files()?
+            .into_iter()
+            .map(PathBuf::from)
+            .filter(|file| file.is_file())
+            .collect();
+        read_kubeconfig_files(&current_kubeconfig_source(), &files)
+    }
+
+    /// Clients are cached per set of kubeconfig files and context, so views on different
+    /// clusters run in parallel instead of rebuilding a single shared client on every switch.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct ClientKey {
+        kubeconfigs: Vec<String>,
+        context: String,
+    }
+
+    struct PooledClient {
+        client: Client,
+        last_used: Instant,
+        last_checked: Instant,
+    }
+
+    // Each key has its own async slot so concurrent callers wait for a single construction
+    // without holding the pool lock across it.
+    type ClientSlot = Arc<AsyncMutex<Option<PooledClient>>>;
+
+    static CLIENT_POOL: Mutex<Option<HashMap<ClientKey, ClientSlot>>> = Mutex::new(None);
+
+    const CLIENT_IDLE_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+    const CLIENT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+    const CLIENT_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
 
     #[tauri::command]
     pub async fn get_current_context() -> Result<String, SerializableKubeError> {
         debug!("Retrieving current Kubernetes context");
-        let config = Kubeconfig::read().map_err(|err| {
-            error!("Failed to read kubeconfig: {}", err);
-            SerializableKubeError::from(err)
-        })?;
+        let config = merge_kubeconfigs(&read_kubeconfigs()?)?;
 
-        let context = config.current_context.ok_or_else(|| SerializableKubeError {
-            message: "No current context set in kubeconfig".to_string(),
-            code: None,
-            reason: Some("NoCurrentContext".to_string()),
-            details: None,
-        })?;
+        let context = config.current_context.ok_or_else(|| SerializableKubeError::new(
+            ErrorKind::Kubeconfig,
+            "NoCurrentContext",
+            "No current context set in kubeconfig".to_string(),
+        ))?;
         
         info!("Current context retrieved: {}", context);
         Ok(context)
     }
 
     #[tauri::command]
-    pub async fn list_contexts() -> Result<Vec<NamedContext>, SerializableKubeError> {
+    pub async fn list_contexts() -> Result<Vec<SourcedContext>, SerializableKubeError> {
         debug!("Listing available Kubernetes contexts");
-        let kubeconfig = {
-            let kubeconfig_guard = CURRENT_KUBECONFIG.lock().unwrap();
-            kubeconfig_guard.as_ref().ok_or_else(|| {
-                error!("No kubeconfig has been set");
-                SerializableKubeError {
-                    message: "No kubeconfig has been set".to_string(),
-                    code: None,
-                    reason: Some("NoKubeconfig".to_string()),
-                    details: None,
-                } 
-            })?.clone()
-        };
-
-        let config = Kubeconfig::read_from(kubeconfig.as_str()).map_err(|err| {
-            error!("Failed to read kubeconfig from path: {}", err);
-            SerializableKubeError::from(err)
-        })?;
+        let configs = read_kubeconfigs()?;
+        let file_count = configs.len();
+
+        // The first file to define a context name wins, same as `Kubeconfig::merge`
+        let mut contexts: Vec<SourcedContext> = Vec::new();
+        for (file, config) in configs {
+            for context in config.contexts {
+                if contexts.iter().any(|existing| existing.context.name == context.name) {
+                    debug!("Ignoring context {} from {}, it is already defined", context.name, file.display());
+                    continue;
+                }
+                contexts.push(SourcedContext {
+                    context,
+                    source: file.to_string_lossy().into_owned(),
+                });
+            }
+        }
 
-        info!("Found {} contexts", config.contexts.len());
-        trace!("Available contexts: {:?}", config.contexts); 
-        Ok(config.contexts)
+        info!("Found {} contexts in {} kubeconfig files", contexts.len(), file_count);
+        trace!("Available contexts: {:?}", contexts); 
+        Ok(contexts)
     }
 
     #[tauri::command]
@@ -136,244 +574,757 @@ pub mod client {
             .iter()
             .find(|c| c.name == context)
             .map(|c| c.clone().context.unwrap().user.clone())
-            .ok_or(SerializableKubeError {
-                message: "Context not found".to_string(),
-                code: None,
-                reason: None,
-                details: None,
-            })?;
+            .ok_or(SerializableKubeError::new(
+                ErrorKind::Kubeconfig,
+                "ContextNotFound",
+                "Context not found".to_string(),
+            ))?;
 
         let auth_info = config
             .auth_infos
             .iter()
             .find(|a| a.name == context_auth_info)
-            .ok_or(SerializableKubeError {
-                message: "Auth info not found".to_string(),
-                code: None,
-                reason: None,
-                details: None,
-            })?;
+            .ok_or(SerializableKubeError::new(
+                ErrorKind::Kubeconfig,
+                "AuthInfoNotFound",
+                "Auth info not found".to_string(),
+            ))?;
 
         return Ok(auth_info.clone());
     }
 
-    async fn one_off_client_with_context(
-        context: &str,
-        kube_config: &str,
-    ) -> Result<Client, SerializableKubeError> {
-        debug!("Creating one-off client for context: {}", context);
+    /// The kubeconfig clients are built from. Clusters and users are resolved first-file-wins
+    /// in KUBECONFIG order, like kubectl does, even when the context itself comes from a later
+    /// file. The kubeconfig watcher goes through here as well, so it reports and invalidates
+    /// exactly the definitions clients use.
+    pub(crate) fn client_kubeconfig(configs: &[(PathBuf, Kubeconfig)]) -> Result<Kubeconfig, SerializableKubeError> {
+        merge_kubeconfigs(configs)
+    }
+
+    /// Creates a client outside of the pool, for one-off checks that should neither reuse
+    /// nor replace the clients views are using.
+    pub(crate) async fn build_client(kubeconfigs: &[String], context: &str) -> Result<Client, SerializableKubeError> {
+        debug!("Creating client for context: {}", context);
         let options = KubeConfigOptions {
             context: Some(context.to_string()),
             cluster: None,
             user: None,
         };
 
-        let kubeconfig = Kubeconfig::read_from(kube_config.to_string()).map_err(|err| {
-            error!("Failed to read kubeconfig for context {}: {}", context, err);
-            SerializableKubeError::from(err)
-        })?;
-        
+        let source = KubeconfigSource::Paths {
+            paths: kubeconfigs.to_vec(),
+        };
+        let files: Vec<PathBuf> = kubeconfigs.iter().map(PathBuf::from).collect();
+        let kubeconfig = client_kubeconfig(&read_kubeconfig_files(&source, &files)?)?;
+
         let client_config = Config::from_custom_kubeconfig(kubeconfig, &options).await.map_err(|err| {
             error!("Failed to create client config for context {}: {}", context, err);
             SerializableKubeError::from(err)
         })?;
 
-        let client = Client::try_from(client_config).map_err(|err| {
+        Client::try_from(client_config).map_err(|err| {
             error!("Failed to create client for context {}: {}", context, err);
             SerializableKubeError::from(err)
-        })?;
-
-        debug!("Successfully created one-off client for context: {}", context);
-        Ok(client)
+        })
     }
 
-    async fn client_with_context(context: &str) -> Result<Client, SerializableKubeError> {
-        debug!("Getting or creating client for context: {}", context);
-        
-        let current_context = CURRENT_CONTEXT.lock().unwrap().as_ref().unwrap().clone();
-        if context.to_string() != current_context {
-            debug!("Context switch detected: {} -> {}", current_context, context);
-            let options = KubeConfigOptions {
-                context: Some(context.to_string()),
-                cluster: None,
-                user: None,
-            };
+    async fn is_healthy(client: &Client) -> bool {
+        matches!(
+            timeout(CLIENT_HEALTH_CHECK_TIMEOUT, client.apiserver_version()).await,
+            Ok(Ok(_))
+        )
+    }
 
-            let current_kubeconfig = CURRENT_KUBECONFIG.lock().unwrap().clone();
-            let client_config = match current_kubeconfig {
-                Some(kubeconfig) if !kubeconfig.is_empty() => {
-                    debug!("Using custom kubeconfig path");
-                    let kubeconfig = Kubeconfig::read_from(kubeconfig.clone()).map_err(|err| {
-                        error!("Failed to read custom kubeconfig: {}", err);
-                        SerializableKubeError::from(err)
-                    })?;
-                    Config::from_custom_kubeconfig(kubeconfig, &options).await.map_err(|err| {
-                        error!("Failed to create config from custom kubeconfig: {}", err);
-                        SerializableKubeError::from(err)
-                    })?
-                }
+    // Slots that are locked are in use and never idle. Long-running watches keep their own
+    // clone of the client, so eviction only drops the cached handle.
+    fn evict_idle_clients(pool: &mut HashMap<ClientKey, ClientSlot>) {
+        pool.retain(|key, slot| match slot.try_lock() {
+            Ok(pooled) => match pooled.as_ref() {
+                Some(pooled) if pooled.last_used.elapsed() < CLIENT_IDLE_TIMEOUT => true,
                 _ => {
-                    debug!("Using default kubeconfig path");
-                    Config::from_kubeconfig(&options).await.map_err(|err| {
-                        error!("Failed to create config from default kubeconfig: {}", err);
-                        SerializableKubeError::from(err)
-                    })?
+                    debug!("Evicting idle client for context {}", key.context);
+                    false
                 }
-            };
-
-            let client = Client::try_from(client_config).map_err(|err| {
-                error!("Failed to create Kubernetes client: {}", err);
-                SerializableKubeError::from(err)
-            })?;
+            },
+            Err(_) => true,
+        });
+    }
 
-            CURRENT_CONTEXT.lock().unwrap().replace(context.to_string());
-            CLIENT.lock().unwrap().replace(client);
-            info!("Successfully switched to context: {}", context);
-        }
+    /// Returns the cached client for the context in the merged kubeconfig files, creating it on
+    /// first use and recreating it when it has not passed a health check within the last minute.
+    pub(crate) async fn pooled_client(
+        kubeconfigs: &[String],
+        context: &str,
+    ) -> Result<Client, SerializableKubeError> {
+        let key = ClientKey {
+            kubeconfigs: kubeconfigs.to_vec(),
+            context: context.to_string(),
+        };
 
-        Ok(CLIENT.lock().unwrap().clone().unwrap())
-    }
+        let slot = {
+            let mut pool = CLIENT_POOL.lock().unwrap();
+            let pool = pool.get_or_insert_with(HashMap::new);
+            evict_idle_clients(pool);
+            pool.entry(key.clone()).or_default().clone()
+        };
 
-    #[tauri::command]
-    pub async fn set_current_kubeconfig(kube_config: &str) -> Result<(), SerializableKubeError> {
-        debug!("Setting current kubeconfig path");
-        
-        // Validate the kubeconfig can be read before setting it
-        match Kubeconfig::read_from(kube_config.to_string()) {
-            Ok(_) => {
-                CURRENT_KUBECONFIG.lock().unwrap().replace(kube_config.to_string());
-                info!("Successfully set new kubeconfig path");
-                Ok(())
-            }
-            Err(err) => {
-                error!("Invalid kubeconfig provided: {}", err);
-                Err(SerializableKubeError::from(err))
+        let mut pooled = slot.lock().await;
+        if let Some(entry) = pooled.as_mut() {
+            if entry.last_checked.elapsed() >= CLIENT_HEALTH_CHECK_INTERVAL {
+                if is_healthy(&entry.client).await {
+                    entry.last_checked = Instant::now();
+                } else {
+                    warn!("Client for context {} failed its health check, recreating it", context);
+                    *pooled = None;
+                }
             }
         }
-    }
 
-    #[tauri::command]
-    pub async fn list_namespaces(
-        context: &str,
-        kube_config: &str,
-    ) -> Result<Vec<Namespace>, SerializableKubeError> {
-        debug!("Listing namespaces for context: {}", context);
-        let client = one_off_client_with_context(context, kube_config).await?;
-        let namespace_api: Api<Namespace> = Api::all(client);
+        if let Some(entry) = pooled.as_mut() {
+            trace!("Reusing cached client for context {}", context);
+            entry.last_used = Instant::now();
+            return Ok(entry.client.clone());
+        }
 
-        let namespaces = namespace_api.list(&ListParams::default()).await.map_err(|err| {
-            error!("Failed to list namespaces: {}", err);
-            SerializableKubeError::from(err)
-        })?;
+        let client = build_client(&key.kubeconfigs, context).await?;
+        info!("Created client for context: {}", context);
+        let now = Instant::now();
+        *pooled = Some(PooledClient {
+            client: client.clone(),
+            last_used: now,
+            last_checked: now,
+        });
+        Ok(client)
+    }
+
+    /// Drops cached clients built from the kubeconfig file for the context, where `None`
+    /// matches everything. Returns how many clients were dropped.
+    pub(crate) fn invalidate_cached_clients(kubeconfig: Option<&str>, context: Option<&str>) -> usize {
+        let mut pool = CLIENT_POOL.lock().unwrap();
+        let Some(pool) = pool.as_mut() else {
+            return 0;
+        };
 
-        info!("Found {} namespaces", namespaces.items.len());
-        Ok(namespaces.items)
+        let before = pool.len();
+        pool.retain(|key, _| {
+            let kubeconfig_matches = kubeconfig.is_none_or(|path| key.kubeconfigs.iter().any(|file| file == path));
+            let context_matches = context.is_none_or(|context| key.context == context);
+            !(kubeconfig_matches && context_matches)
+        });
+        before - pool.len()
     }
 
     #[tauri::command]
-    pub async fn list_pods(
-        context: &str,
-        namespace: &str,
-        label_selector: &str,
-        field_selector: &str,
-    ) -> Result<Vec<Pod>, SerializableKubeError> {
-        debug!("Listing pods in namespace {} for context: {}", namespace, context);
-        trace!("Using selectors - label: {}, field: {}", label_selector, field_selector);
-        
-        let client = client_with_context(context).await?;
-        let pod_api: Api<Pod> = Api::namespaced(client, namespace);
+    pub async fn invalidate_clients(kube_config: Option<String>, context: Option<String>) -> usize {
+        let invalidated = invalidate_cached_clients(kube_config.as_deref(), context.as_deref());
+        info!("Invalidated {} cached clients", invalidated);
+        invalidated
+    }
 
-        let pods = pod_api.list(
-            &ListParams::default()
-                .labels(label_selector)
-                .fields(field_selector),
-        ).await.map_err(|err| {
-            error!("Failed to list pods in namespace {}: {}", namespace, err);
-            SerializableKubeError::from(err)
-        })?;
+    pub(crate) async fn client_with_context(context: &str) -> Result<Client, SerializableKubeError> {
+        debug!("Getting or creating client for context: {}", context);
+        let kubeconfigs = current_kubeconfig_files()?;
+        pooled_client(&kubeconfigs, context).await
+    }
 
-        info!("Found {} pods in namespace {}", pods.items.len(), namespace);
-        Ok(pods.items)
+    /// Per-call settings accepted by the commands in this module.
+    #[derive(Clone, Debug, Default, Deserialize)]
+    pub struct RequestOptions {
+        /// Id under which `cancel_request` can abort the command.
+        pub request_id: Option<String>,
+        /// Deadline for creating the client and for each attempt, not for the command as a
+        /// whole.
+        pub timeout_seconds: Option<u64>,
+        /// How often a read that failed with a retryable error is sent again. Writes are
+        /// never retried.
+        pub retries: Option<u32>,
     }
 
-    #[tauri::command]
-    pub async fn get_pod_metrics(
-        context: &str,
-        namespace: &str,
-    ) -> Result<Vec<PodMetrics>, SerializableKubeError> {
-        debug!("Fetching pod metrics for namespace {} in context {}", namespace, context);
-        let client = client_with_context(context).await?;
-        let metrics_api: Api<PodMetrics> = Api::namespaced(client, namespace);
+    struct InFlightRequest {
+        generation: u64,
+        cancel: watch::Sender<bool>,
+    }
 
-        let metrics = metrics_api.list(&ListParams::default()).await.map_err(|err| {
-            error!("Failed to get pod metrics for namespace {}: {}", namespace, err);
-            SerializableKubeError::from(err)
-        })?;
+    static IN_FLIGHT_REQUESTS: Mutex<Option<HashMap<String, InFlightRequest>>> = Mutex::new(None);
+    static REQUEST_GENERATION: AtomicU64 = AtomicU64::new(0);
 
-        info!("Retrieved metrics for {} pods in namespace {}", metrics.items.len(), namespace);
-        Ok(metrics.items)
+    const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+    const DEFAULT_READ_RETRIES: u32 = 2;
+    const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+    const RETRY_MAX_DELAY: Duration = Duration::from_secs(5);
+
+    // Unregisters the request when its command finishes, unless a newer call took over the id
+    struct RequestRegistration {
+        request_id: String,
+        generation: u64,
+        cancelled: watch::Receiver<bool>,
     }
 
-    #[tauri::command]
-    pub async fn get_pod_metric(
-        context: &str,
-        namespace: &str,
-        name: &str,
-    ) -> Result<PodMetrics, SerializableKubeError> {
-        debug!("Fetching metrics for pod {}/{}", namespace, name);
-        let client = client_with_context(context).await?;
-        let metrics_api: Api<PodMetrics> = Api::namespaced(client, namespace);
+    impl Drop for RequestRegistration {
+        fn drop(&mut self) {
+            let mut requests = IN_FLIGHT_REQUESTS.lock().unwrap();
+            if let Some(requests) = requests.as_mut() {
+                if requests
+                    .get(&self.request_id)
+                    .is_some_and(|request| request.generation == self.generation)
+                {
+                    requests.remove(&self.request_id);
+                }
+            }
+        }
+    }
 
-        let metric = metrics_api.get(name).await.map_err(|err| {
-            error!("Failed to get metrics for pod {}/{}: {}", namespace, name, err);
-            SerializableKubeError::from(err)
-        })?;
+    // A request id that is still in flight belongs to a view that has moved on, so the
+    // older call is cancelled in favour of the new one.
+    fn register_request(request_id: &str) -> RequestRegistration {
+        let generation = REQUEST_GENERATION.fetch_add(1, Ordering::Relaxed);
+        let (cancel, cancelled) = watch::channel(false);
+        let mut requests = IN_FLIGHT_REQUESTS.lock().unwrap();
+        let previous = requests
+            .get_or_insert_with(HashMap::new)
+            .insert(request_id.to_string(), InFlightRequest { generation, cancel });
+        if let Some(previous) = previous {
+            debug!("Request {} superseded by a newer call", request_id);
+            let _ = previous.cancel.send(true);
+        }
 
-        info!("Successfully retrieved metrics for pod {}/{}", namespace, name);
-        Ok(metric)
+        RequestRegistration {
+            request_id: request_id.to_string(),
+            generation,
+            cancelled,
+        }
     }
 
-    #[tauri::command]
-    pub async fn get_pod(
-        context: &str,
-        namespace: &str,
-        name: &str,
-    ) -> Result<Pod, SerializableKubeError> {
-        let client = client_with_context(context).await?;
-        let pod_api: Api<Pod> = Api::namespaced(client, namespace);
-
-        return pod_api
-            .get(name)
-            .await
-            .map_err(|err| SerializableKubeError::from(err));
+    async fn attempt<T, E, Fut>(deadline: Duration, call: Fut) -> Result<T, SerializableKubeError>
+    where
+        Fut: Future<Output = Result<T, E>>,
+        SerializableKubeError: From<E>,
+    {
+        match timeout(deadline, call).await {
+            Ok(result) => result.map_err(SerializableKubeError::from),
+            Err(_) => Err(SerializableKubeError::new(
+                ErrorKind::Timeout,
+                "Timeout",
+                format!("Request did not complete within {}s", deadline.as_secs()),
+            )),
+        }
     }
 
-    #[tauri::command]
-    pub async fn delete_pod(
-        context: &str,
-        namespace: &str,
-        name: &str,
-        grace_period_seconds: u32,
-    ) -> Result<DeletionResult, SerializableKubeError> {
-        debug!("Deleting pod {}/{} with grace period {}s", namespace, name, grace_period_seconds);
-        let client = client_with_context(context).await?;
-        let pod_api: Api<Pod> = Api::namespaced(client, namespace);
+    /// The deadline, retries and cancellation in `RequestOptions`, applied to one command
+    /// from creating its client to its last request. The request id is registered for as
+    /// long as the scope lives, so `cancel_request` also stops a command that is still
+    /// connecting or is between two requests.
+    pub(crate) struct RequestScope {
+        deadline: Duration,
+        retries: Option<u32>,
+        registration: Option<RequestRegistration>,
+    }
 
-        match pod_api
-            .delete(name, &DeleteParams::default().grace_period(grace_period_seconds))
-            .await
-        {
-            Ok(Either::Left(_pod)) => {
-                info!("Pod {}/{} deleted successfully", namespace, name);
-                Ok(DeletionResult::Deleted(name.to_string()))
+    impl RequestScope {
+        pub(crate) fn new(options: Option<&RequestOptions>) -> Self {
+            RequestScope {
+                deadline: options
+                    .and_then(|options| options.timeout_seconds)
+                    .map(Duration::from_secs)
+                    .unwrap_or(DEFAULT_REQUEST_TIMEOUT),
+                retries: options.and_then(|options| options.retries),
+                registration: options
+                    .and_then(|options| options.request_id.as_deref())
+                    .map(register_request),
             }
-            Ok(Either::Right(_status)) => {
-                debug!("Pod {}/{} deletion in progress", namespace, name);
+        }
+
+        /// Runs `work` until it completes or the request is cancelled.
+        pub(crate) async fn cancellable<T>(
+            &self,
+            work: impl Future<Output = Result<T, SerializableKubeError>>,
+        ) -> Result<T, SerializableKubeError> {
+            let Some(registration) = &self.registration else {
+                return work.await;
+            };
+
+            let mut cancelled = registration.cancelled.clone();
+            let cancellation = async {
+                while !*cancelled.borrow_and_update() {
+                    // The sender only goes away without cancelling once this scope is done
+                    if cancelled.changed().await.is_err() {
+                        std::future::pending::<()>().await;
+                    }
+                }
+            };
+
+            // Checking for cancellation first keeps a cancelled scope from starting new work
+            tokio::select! {
+                biased;
+                _ = cancellation => {
+                    info!("Request {} was cancelled", registration.request_id);
+                    Err(SerializableKubeError::new(
+                        ErrorKind::Cancelled,
+                        "Cancelled",
+                        format!("Request {} was cancelled", registration.request_id),
+                    ))
+                }
+                result = work => result,
+            }
+        }
+
+        /// Creates or reuses the client of `context` within the deadline.
+        pub(crate) async fn client(&self, context: &str) -> Result<Client, SerializableKubeError> {
+            self.connect(client_with_context(context)).await
+        }
+
+        /// Awaits the creation of a client within the deadline.
+        pub(crate) async fn connect(
+            &self,
+            client: impl Future<Output = Result<Client, SerializableKubeError>>,
+        ) -> Result<Client, SerializableKubeError> {
+            self.cancellable(attempt(self.deadline, client)).await
+        }
+
+        async fn send<T, E, F, Fut>(&self, retries: u32, mut call: F) -> Result<T, SerializableKubeError>
+        where
+            F: FnMut() -> Fut,
+            Fut: Future<Output = Result<T, E>>,
+            SerializableKubeError: From<E>,
+        {
+            self.cancellable(async {
+                let mut delay = RETRY_BASE_DELAY;
+                let mut retried = 0;
+                loop {
+                    match attempt(self.deadline, call()).await {
+                        Err(err) if retried < retries && err.kind.is_retryable() => {
+                            warn!("Request failed ({:?}: {}), retrying in {:?}", err.kind, err.message, delay);
+                            tokio::time::sleep(delay).await;
+                            delay = (delay * 2).min(RETRY_MAX_DELAY);
+                            retried += 1;
+                        }
+                        result => return result,
+                    }
+                }
+            })
+            .await
+        }
+
+        /// Sends an idempotent request, retrying it on retryable errors.
+        pub(crate) async fn read<T, E, F, Fut>(&self, call: F) -> Result<T, SerializableKubeError>
+        where
+            F: FnMut() -> Fut,
+            Fut: Future<Output = Result<T, E>>,
+            SerializableKubeError: From<E>,
+        {
+            self.send(self.retries.unwrap_or(DEFAULT_READ_RETRIES), call).await
+        }
+
+        /// Sends a request that changes the cluster. It gets a deadline and can be cancelled,
+        /// but is sent only once since a failed attempt may still have been applied.
+        pub(crate) async fn write<T, E, F, Fut>(&self, call: F) -> Result<T, SerializableKubeError>
+        where
+            F: FnMut() -> Fut,
+            Fut: Future<Output = Result<T, E>>,
+            SerializableKubeError: From<E>,
+        {
+            self.send(0, call).await
+        }
+    }
+
+    #[tauri::command]
+    pub async fn cancel_request(request_id: &str) -> bool {
+        let request = IN_FLIGHT_REQUESTS
+            .lock()
+            .unwrap()
+            .as_mut()
+            .and_then(|requests| requests.remove(request_id));
+
+        match request {
+            Some(request) => {
+                debug!("Cancelling request {}", request_id);
+                let _ = request.cancel.send(true);
+                true
+            }
+            None => {
+                debug!("No request {} in flight to cancel", request_id);
+                false
+            }
+        }
+    }
+
+    /// Opts a list command into pagination. Without a `limit` every page is fetched in
+    /// chunks and reduced to `columns` as it arrives, so `columns` is required then.
+    #[derive(Clone, Debug, Default, Deserialize)]
+    pub struct PageOptions {
+        pub limit: Option<u32>,
+        pub continue_token: Option<String>,
+        /// Dotted paths such as `status.phase` to keep next to the metadata; the rest of
+        /// each object is dropped. Paths through arrays apply to every element.
+        pub columns: Option<Vec<String>>,
+    }
+
+    #[derive(Debug, Serialize)]
+    pub struct ListPage {
+        items: Vec<Value>,
+        /// Passed back as `continue_token` to fetch the next page; `None` on the last page.
+        continue_token: Option<String>,
+        remaining_item_count: Option<i64>,
+        resource_version: Option<String>,
+    }
+
+    /// A plain list when the command was called without `PageOptions`, so existing callers
+    /// keep receiving an array.
+    #[derive(Debug, Serialize)]
+    #[serde(untagged)]
+    pub enum ListResult<K> {
+        Items(Vec<K>),
+        Page(ListPage),
+    }
+
+    impl<K> ListResult<K> {
+        pub(crate) fn len(&self) -> usize {
+            match self {
+                ListResult::Items(items) => items.len(),
+                ListResult::Page(page) => page.items.len(),
+            }
+        }
+    }
+
+    // Same chunk size kubectl uses when listing
+    const LIST_CHUNK_SIZE: u32 = 500;
+    const LAST_APPLIED_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+    // Managed fields and the last applied configuration are often larger than the rest of
+    // the object, and no list view shows them.
+    fn trim_metadata(metadata: &mut Value) {
+        if let Some(metadata) = metadata.as_object_mut() {
+            metadata.remove("managedFields");
+            if let Some(annotations) = metadata.get_mut("annotations").and_then(Value::as_object_mut) {
+                annotations.remove(LAST_APPLIED_ANNOTATION);
+            }
+        }
+    }
+
+    fn copy_path(source: &Value, target: &mut Map<String, Value>, path: &[&str]) {
+        let Some((key, rest)) = path.split_first() else {
+            return;
+        };
+        let Some(value) = source.get(*key) else {
+            return;
+        };
+        if rest.is_empty() {
+            target.insert(key.to_string(), value.clone());
+            return;
+        }
+
+        match value {
+            Value::Object(_) => {
+                let entry = target
+                    .entry(key.to_string())
+                    .or_insert_with(|| Value::Object(Map::new()));
+                if let Value::Object(entry) = entry {
+                    copy_path(value, entry, rest);
+                }
+            }
+            Value::Array(elements) => {
+                let entry = target
+                    .entry(key.to_string())
+                    .or_insert_with(|| Value::Array(vec![Value::Object(Map::new()); elements.len()]));
+                if let Value::Array(entries) = entry {
+                    for (element, entry) in elements.iter().zip(entries.iter_mut()) {
+                        if let Value::Object(entry) = entry {
+                            copy_path(element, entry, rest);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn project(object: &Value, columns: &[String]) -> Value {
+        let mut projected = Map::new();
+        for key in ["apiVersion", "kind", "metadata"] {
+            if let Some(value) = object.get(key) {
+                projected.insert(key.to_string(), value.clone());
+            }
+        }
+        if let Some(metadata) = projected.get_mut("metadata") {
+            trim_metadata(metadata);
+        }
+
+        for column in columns {
+            let path: Vec<&str> = column.split('.').collect();
+            copy_path(object, &mut projected, &path);
+        }
+        Value::Object(projected)
+    }
+
+    /// Lists with `params`, one page at a time when `page` is given.
+    pub(crate) async fn list_objects<K>(
+        api: &Api<K>,
+        params: ListParams,
+        page: Option<PageOptions>,
+        scope: &RequestScope,
+    ) -> Result<ListResult<K>, SerializableKubeError>
+    where
+        K: Clone + DeserializeOwned + Serialize + std::fmt::Debug,
+    {
+        let Some(page) = page else {
+            let list = scope.read(|| api.list(&params)).await?;
+            return Ok(ListResult::Items(list.items));
+        };
+
+        collect_pages(params, &page, |params| async move { scope.read(|| api.list(&params)).await }).await
+    }
+
+    // Follows the continue tokens of a chunked list when no limit was asked for
+    async fn collect_pages<K, F, Fut>(
+        params: ListParams,
+        page: &PageOptions,
+        mut fetch: F,
+    ) -> Result<ListResult<K>, SerializableKubeError>
+    where
+        K: Clone + Serialize,
+        F: FnMut(ListParams) -> Fut,
+        Fut: Future<Output = Result<ObjectList<K>, SerializableKubeError>>,
+    {
+        if page.limit.is_none() && page.columns.is_none() {
+            return Err(SerializableKubeError::new(
+                ErrorKind::Invalid,
+                "LimitRequired",
+                "Listing every page requires a limit or the columns to keep".to_string(),
+            ));
+        }
+
+        let mut params = params.limit(page.limit.unwrap_or(LIST_CHUNK_SIZE));
+        if let Some(continue_token) = &page.continue_token {
+            params = params.continue_token(continue_token);
+        }
+
+        let mut items = Vec::new();
+        loop {
+            let list = fetch(params.clone()).await?;
+            for item in list.items {
+                let object = serde_json::to_value(item).map_err(|err| {
+                    SerializableKubeError::new(ErrorKind::Unknown, "SerializationFailed", err.to_string())
+                })?;
+                items.push(match &page.columns {
+                    Some(columns) => project(&object, columns),
+                    None => object,
+                });
+            }
+
+            let continue_token = list.metadata.continue_.filter(|token| !token.is_empty());
+            match continue_token {
+                Some(continue_token) if page.limit.is_none() => {
+                    trace!("Fetching next chunk of {} items", LIST_CHUNK_SIZE);
+                    params = params.continue_token(&continue_token);
+                }
+                continue_token => {
+                    return Ok(ListResult::Page(ListPage {
+                        items,
+                        continue_token,
+                        remaining_item_count: list.metadata.remaining_item_count,
+                        resource_version: list.metadata.resource_version,
+                    }));
+                }
+            }
+        }
+    }
+
+    fn set_kubeconfig_source(
+        app_handle: tauri::AppHandle,
+        source: KubeconfigSource,
+    ) -> Result<(), SerializableKubeError> {
+        // Validate the kubeconfigs can be read and merged before switching to them
+        let files = kubeconfig_files(&source)?;
+        let configs = read_kubeconfig_files(&source, &files)?;
+        merge_kubeconfigs(&configs)?;
+        activate_kubeconfigs(source.clone(), &configs);
+
+        // Selecting a kubeconfig again usually means it was edited, so drop its clients.
+        // Edits made while it is selected are picked up by the watcher instead.
+        for (file, _) in &configs {
+            invalidate_cached_clients(Some(&file.to_string_lossy()), None);
+        }
+        watch_kubeconfigs(app_handle, &source);
+        info!("Using {} kubeconfig files", configs.len());
+        Ok(())
+    }
+
+    #[tauri::command]
+    pub async fn set_current_kubeconfig(
+        app_handle: tauri::AppHandle,
+        kube_config: &str,
+    ) -> Result<(), SerializableKubeError> {
+        debug!("Setting current kubeconfig path");
+        let source = if kube_config.is_empty() {
+            KubeconfigSource::Default
+        } else {
+            KubeconfigSource::Paths {
+                paths: vec![kube_config.to_string()],
+            }
+        };
+
+        set_kubeconfig_source(app_handle, source).inspect_err(|err| {
+            error!("Invalid kubeconfig provided: {}", err.message);
+        })
+    }
+
+    #[tauri::command]
+    pub async fn set_kubeconfig_sources(
+        app_handle: tauri::AppHandle,
+        source: KubeconfigSource,
+    ) -> Result<(), SerializableKubeError> {
+        debug!("Setting kubeconfig source: {:?}", source);
+        set_kubeconfig_source(app_handle, source).inspect_err(|err| {
+            error!("Invalid kubeconfig source provided: {}", err.message);
+        })
+    }
+
+    #[tauri::command]
+    pub async fn list_kubeconfig_files() -> Result<Vec<String>, SerializableKubeError> {
+        current_kubeconfig_files()
+    }
+
+    #[tauri::command]
+    pub async fn list_namespaces(
+        context: &str,
+        kube_config: &str,
+        page: Option<PageOptions>,
+        options: Option<RequestOptions>,
+    ) -> Result<ListResult<Namespace>, SerializableKubeError> {
+        debug!("Listing namespaces for context: {}", context);
+        let kubeconfigs = match kube_config {
+            "" => current_kubeconfig_files()?,
+            kube_config => vec![kube_config.to_string()],
+        };
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.connect(pooled_client(&kubeconfigs, context)).await?;
+        let namespace_api: Api<Namespace> = Api::all(client);
+
+        let namespaces = list_objects(&namespace_api, ListParams::default(), page, &scope)
+            .await
+            .inspect_err(|err| {
+                error!("Failed to list namespaces: {}", err.message);
+            })?;
+
+        info!("Found {} namespaces", namespaces.len());
+        Ok(namespaces)
+    }
+
+    #[tauri::command]
+    pub async fn list_pods(
+        context: &str,
+        namespace: &str,
+        label_selector: &str,
+        field_selector: &str,
+        page: Option<PageOptions>,
+        options: Option<RequestOptions>,
+    ) -> Result<ListResult<Pod>, SerializableKubeError> {
+        debug!("Listing pods in namespace {} for context: {}", namespace, context);
+        trace!("Using selectors - label: {}, field: {}", label_selector, field_selector);
+        
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        let pod_api: Api<Pod> = Api::namespaced(client, namespace);
+
+        let params = ListParams::default()
+            .labels(label_selector)
+            .fields(field_selector);
+        let pods = list_objects(&pod_api, params, page, &scope)
+            .await
+            .inspect_err(|err| {
+                error!("Failed to list pods in namespace {}: {}", namespace, err.message);
+            })?;
+
+        info!("Found {} pods in namespace {}", pods.len(), namespace);
+        Ok(pods)
+    }
+
+    #[tauri::command]
+    pub async fn get_pod_metrics(
+        context: &str,
+        namespace: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<Vec<PodMetrics>, SerializableKubeError> {
+        debug!("Fetching pod metrics for namespace {} in context {}", namespace, context);
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        let metrics_api: Api<PodMetrics> = Api::namespaced(client, namespace);
+
+        let params = ListParams::default();
+        let metrics = scope.read(|| metrics_api.list(&params))
+            .await
+            .map_err(|err| {
+                error!("Failed to get pod metrics for namespace {}: {}", namespace, err.message);
+                metrics_error(err)
+            })?;
+
+        info!("Retrieved metrics for {} pods in namespace {}", metrics.items.len(), namespace);
+        Ok(metrics.items)
+    }
+
+    #[tauri::command]
+    pub async fn get_pod_metric(
+        context: &str,
+        namespace: &str,
+        name: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<PodMetrics, SerializableKubeError> {
+        debug!("Fetching metrics for pod {}/{}", namespace, name);
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        let metrics_api: Api<PodMetrics> = Api::namespaced(client, namespace);
+
+        let metric = scope.read(|| metrics_api.get(name))
+            .await
+            .map_err(|err| {
+                error!("Failed to get metrics for pod {}/{}: {}", namespace, name, err.message);
+                metrics_error(err)
+            })?;
+
+        info!("Successfully retrieved metrics for pod {}/{}", namespace, name);
+        Ok(metric)
+    }
+
+    #[tauri::command]
+    pub async fn get_pod(
+        context: &str,
+        namespace: &str,
+        name: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<Pod, SerializableKubeError> {
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        let pod_api: Api<Pod> = Api::namespaced(client, namespace);
+
+        return scope.read(|| pod_api.get(name)).await;
+    }
+
+    #[tauri::command]
+    pub async fn delete_pod(
+        context: &str,
+        namespace: &str,
+        name: &str,
+        grace_period_seconds: u32,
+        options: Option<RequestOptions>,
+    ) -> Result<DeletionResult, SerializableKubeError> {
+        debug!("Deleting pod {}/{} with grace period {}s", namespace, name, grace_period_seconds);
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        let pod_api: Api<Pod> = Api::namespaced(client, namespace);
+
+        let params = DeleteParams::default().grace_period(grace_period_seconds);
+        match scope.write(|| pod_api.delete(name, &params)).await {
+            Ok(Either::Left(_pod)) => {
+                info!("Pod {}/{} deleted successfully", namespace, name);
+                Ok(DeletionResult::Deleted(name.to_string()))
+            }
+            Ok(Either::Right(_status)) => {
+                debug!("Pod {}/{} deletion in progress", namespace, name);
                 Ok(DeletionResult::Pending("Deletion in progress".to_string()))
             }
             Err(err) => {
-                error!("Failed to delete pod {}/{}: {}", namespace, name, err);
-                Err(SerializableKubeError::from(err))
+                error!("Failed to delete pod {}/{}: {}", namespace, name, err.message);
+                Err(err)
             }
         }
     }
@@ -382,57 +1333,66 @@ pub mod client {
     pub async fn list_deployments(
         context: &str,
         namespace: &str,
-    ) -> Result<Vec<Deployment>, SerializableKubeError> {
-        let client = client_with_context(context).await?;
+        page: Option<PageOptions>,
+        options: Option<RequestOptions>,
+    ) -> Result<ListResult<Deployment>, SerializableKubeError> {
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
         let deployment_api: Api<Deployment> = Api::namespaced(client, namespace);
 
-        return deployment_api
-            .list(&ListParams::default())
-            .await
-            .map(|deployments| deployments.items)
-            .map_err(|err| SerializableKubeError::from(err));
+        return list_objects(&deployment_api, ListParams::default(), page, &scope).await;
     }
 
     #[tauri::command]
     pub async fn restart_deployment(
+        app_handle: tauri::AppHandle,
         context: &str,
         namespace: &str,
         name: &str,
+        rollout_tracking_id: Option<String>,
+        options: Option<RequestOptions>,
     ) -> Result<bool, SerializableKubeError> {
         debug!("Restarting deployment {}/{}", namespace, name);
-        let client = client_with_context(context).await?;
-        let deployment_api: Api<Deployment> = Api::namespaced(client, namespace);
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        let deployment_api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
 
-        match deployment_api.restart(name).await {
+        match scope.write(|| deployment_api.restart(name)).await {
             Ok(_) => {
                 info!("Successfully restarted deployment {}/{}", namespace, name);
+                track_rollout_if_requested(&app_handle, client, namespace, "deployment", name, rollout_tracking_id);
                 Ok(true)
             }
             Err(err) => {
-                error!("Failed to restart deployment {}/{}: {}", namespace, name, err);
-                Err(SerializableKubeError::from(err))
+                error!("Failed to restart deployment {}/{}: {}", namespace, name, err.message);
+                Err(err)
             }
         }
     }
 
     #[tauri::command]
     pub async fn restart_statefulset(
+        app_handle: tauri::AppHandle,
         context: &str,
         namespace: &str,
         name: &str,
+        rollout_tracking_id: Option<String>,
+        options: Option<RequestOptions>,
     ) -> Result<bool, SerializableKubeError> {
         debug!("Restarting statefulset {}/{}", namespace, name);
-        let client = client_with_context(context).await?;
-        let statefulset_api: Api<StatefulSet> = Api::namespaced(client, namespace);
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        let statefulset_api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
 
-        match statefulset_api.restart(name).await {
+        match scope.write(|| statefulset_api.restart(name)).await {
             Ok(_) => {
                 info!("Successfully restarted statefulset {}/{}", namespace, name);
+                track_rollout_if_requested(&app_handle, client, namespace, "statefulset", name, rollout_tracking_id);
                 Ok(true)
             }
             Err(err) => {
-                error!("Failed to restart statefulset {}/{}: {}", namespace, name, err);
-                Err(SerializableKubeError::from(err))
+                error!("Failed to restart statefulset {}/{}: {}", namespace, name, err.message);
+                Err(err)
             }
         }
     }
@@ -441,122 +1401,119 @@ pub mod client {
     pub async fn list_services(
         context: &str,
         namespace: &str,
-    ) -> Result<Vec<Service>, SerializableKubeError> {
-        let client = client_with_context(context).await?;
+        page: Option<PageOptions>,
+        options: Option<RequestOptions>,
+    ) -> Result<ListResult<Service>, SerializableKubeError> {
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
         let services_api: Api<Service> = Api::namespaced(client, namespace);
 
-        return services_api
-            .list(&ListParams::default())
-            .await
-            .map(|services| services.items)
-            .map_err(|err| SerializableKubeError::from(err));
+        return list_objects(&services_api, ListParams::default(), page, &scope).await;
     }
 
     #[tauri::command]
     pub async fn list_jobs(
         context: &str,
         namespace: &str,
-    ) -> Result<Vec<Job>, SerializableKubeError> {
-        let client = client_with_context(context).await?;
+        page: Option<PageOptions>,
+        options: Option<RequestOptions>,
+    ) -> Result<ListResult<Job>, SerializableKubeError> {
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
         let jobs_api: Api<Job> = Api::namespaced(client, namespace);
 
-        return jobs_api
-            .list(&ListParams::default())
-            .await
-            .map(|jobs| jobs.items)
-            .map_err(|err| SerializableKubeError::from(err));
+        return list_objects(&jobs_api, ListParams::default(), page, &scope).await;
     }
 
     #[tauri::command]
     pub async fn list_cronjobs(
         context: &str,
         namespace: &str,
-    ) -> Result<Vec<CronJob>, SerializableKubeError> {
-        let client = client_with_context(context).await?;
+        page: Option<PageOptions>,
+        options: Option<RequestOptions>,
+    ) -> Result<ListResult<CronJob>, SerializableKubeError> {
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
         let cronjobs_api: Api<CronJob> = Api::namespaced(client, namespace);
 
-        return cronjobs_api
-            .list(&ListParams::default())
-            .await
-            .map(|cronjobs| cronjobs.items)
-            .map_err(|err| SerializableKubeError::from(err));
+        return list_objects(&cronjobs_api, ListParams::default(), page, &scope).await;
     }
 
     #[tauri::command]
     pub async fn list_configmaps(
         context: &str,
         namespace: &str,
-    ) -> Result<Vec<ConfigMap>, SerializableKubeError> {
-        let client: Client = client_with_context(context).await?;
+        page: Option<PageOptions>,
+        options: Option<RequestOptions>,
+    ) -> Result<ListResult<ConfigMap>, SerializableKubeError> {
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
         let configmaps_api: Api<ConfigMap> = Api::namespaced(client, namespace);
 
-        return configmaps_api
-            .list(&ListParams::default())
-            .await
-            .map(|configmaps| configmaps.items)
-            .map_err(|err| SerializableKubeError::from(err));
+        return list_objects(&configmaps_api, ListParams::default(), page, &scope).await;
     }
 
     #[tauri::command]
     pub async fn list_secrets(
         context: &str,
         namespace: &str,
-    ) -> Result<Vec<Secret>, SerializableKubeError> {
-        let client: Client = client_with_context(context).await?;
+        page: Option<PageOptions>,
+        options: Option<RequestOptions>,
+    ) -> Result<ListResult<Secret>, SerializableKubeError> {
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
         let secrets_api: Api<Secret> = Api::namespaced(client, namespace);
 
-        return secrets_api
-            .list(&ListParams::default())
-            .await
-            .map(|secrets| secrets.items)
-            .map_err(|err| SerializableKubeError::from(err));
+        return list_objects(&secrets_api, ListParams::default(), page, &scope).await;
     }
 
     #[tauri::command]
     pub async fn list_ingresses(
         context: &str,
         namespace: &str,
-    ) -> Result<Vec<Ingress>, SerializableKubeError> {
-        let client: Client = client_with_context(context).await?;
+        page: Option<PageOptions>,
+        options: Option<RequestOptions>,
+    ) -> Result<ListResult<Ingress>, SerializableKubeError> {
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
         let ingress_api: Api<Ingress> = Api::namespaced(client, namespace);
 
-        return ingress_api
-            .list(&ListParams::default())
-            .await
-            .map(|ingresses| ingresses.items)
-            .map_err(|err| SerializableKubeError::from(err));
+        return list_objects(&ingress_api, ListParams::default(), page, &scope).await;
     }
 
     #[tauri::command]
     pub async fn list_persistentvolumes(
         context: &str,
-    ) -> Result<Vec<PersistentVolume>, SerializableKubeError> {
+        page: Option<PageOptions>,
+        options: Option<RequestOptions>,
+    ) -> Result<ListResult<PersistentVolume>, SerializableKubeError> {
         debug!("Listing persistent volumes in context {}", context);
-        let client: Client = client_with_context(context).await?;
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
         let pv_api: Api<PersistentVolume> = Api::all(client);
 
-        let pvs = pv_api.list(&ListParams::default()).await.map_err(|err| {
-            error!("Failed to list persistent volumes: {}", err);
-            SerializableKubeError::from(err)
-        })?;
+        let pvs = list_objects(&pv_api, ListParams::default(), page, &scope)
+            .await
+            .inspect_err(|err| {
+                error!("Failed to list persistent volumes: {}", err.message);
+            })?;
 
-        info!("Found {} persistent volumes", pvs.items.len());
-        Ok(pvs.items)
+        info!("Found {} persistent volumes", pvs.len());
+        Ok(pvs)
     }
 
     #[tauri::command]
     pub async fn list_persistentvolumeclaims(
         context: &str,
         namespace: &str,
-    ) -> Result<Vec<PersistentVolumeClaim>, SerializableKubeError> {
-        let client: Client = client_with_context(context).await?;
+        page: Option<PageOptions>,
+        options: Option<RequestOptions>,
+    ) -> Result<ListResult<PersistentVolumeClaim>, SerializableKubeError> {
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
         let pvc_api: Api<PersistentVolumeClaim> = Api::namespaced(client, namespace);
 
-        return pvc_api
-            .list(&ListParams::default())
-            .await
-            .map(|pvcs| pvcs.items)
-            .map_err(|err| SerializableKubeError::from(err));
+        return list_objects(&pvc_api, ListParams::default(), page, &scope).await;
     }
 
     #[tauri::command]
@@ -565,15 +1522,19 @@ pub mod client {
         namespace: &str,
         name: &str,
         object: Pod,
+        options: Option<RequestOptions>,
     ) -> Result<Pod, SerializableKubeError> {
         debug!("Replacing pod {}/{}", namespace, name);
-        let client = client_with_context(context).await?;
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
         let pod_api: Api<Pod> = Api::namespaced(client, namespace);
 
-        let pod = pod_api.replace(name, &Default::default(), &object).await.map_err(|err| {
-            error!("Failed to replace pod {}/{}: {}", namespace, name, err);
-            SerializableKubeError::from(err)
-        })?;
+        let params = PostParams::default();
+        let pod = scope.write(|| pod_api.replace(name, &params, &object))
+            .await
+            .inspect_err(|err| {
+                error!("Failed to replace pod {}/{}: {}", namespace, name, err.message);
+            })?;
 
         info!("Successfully replaced pod {}/{}", namespace, name);
         Ok(pod)
@@ -584,7 +1545,7 @@ pub mod client {
         namespace: &str,
         name: &str,
         operation: &str,
-        result: Result<T, Error>,
+        result: Result<T, SerializableKubeError>,
     ) -> Result<T, SerializableKubeError> {
         match result {
             Ok(resource) => {
@@ -592,8 +1553,8 @@ pub mod client {
                 Ok(resource)
             }
             Err(err) => {
-                error!("Failed to {} {} {}/{}: {}", operation, resource_type, namespace, name, err);
-                Err(SerializableKubeError::from(err))
+                error!("Failed to {} {} {}/{}: {}", operation, resource_type, namespace, name, err.message);
+                Err(err)
             }
         }
     }
@@ -607,12 +1568,15 @@ pub mod client {
                 namespace: &str,
                 name: &str,
                 object: $type,
+                options: Option<RequestOptions>,
             ) -> Result<$type, SerializableKubeError> {
                 debug!("Replacing {} {}/{}", $resource_name, namespace, name);
-                let client = client_with_context(context).await?;
+                let scope = RequestScope::new(options.as_ref());
+                let client = scope.client(context).await?;
                 let api: Api<$type> = Api::namespaced(client, namespace);
 
-                let result = api.replace(name, &Default::default(), &object).await;
+                let params = PostParams::default();
+                let result = scope.write(|| api.replace(name, &params, &object)).await;
                 log_resource_operation($resource_name, namespace, name, "replace", result).await
             }
         };
@@ -628,17 +1592,63 @@ pub mod client {
     impl_replace_resource!(replace_ingress, Ingress, "ingress");
     impl_replace_resource!(replace_persistentvolumeclaim, PersistentVolumeClaim, "persistent volume claim");
 
+    // Resource server-side apply operations with logging
+    macro_rules! impl_apply_resource {
+        ($name:ident, $type:ty, $resource_name:expr) => {
+            #[tauri::command]
+            #[allow(clippy::too_many_arguments)]
+            pub async fn $name(
+                app_handle: tauri::AppHandle,
+                context: &str,
+                namespace: &str,
+                name: &str,
+                mut object: $type,
+                force: bool,
+                rollout_tracking_id: Option<String>,
+                options: Option<RequestOptions>,
+            ) -> Result<$type, SerializableKubeError> {
+                debug!("Applying {} {}/{} (force: {})", $resource_name, namespace, name, force);
+                let scope = RequestScope::new(options.as_ref());
+                let client = scope.client(context).await?;
+                let api: Api<$type> = Api::namespaced(client.clone(), namespace);
+
+                prepare_for_apply(&mut object.metadata);
+                let params = apply_params(force);
+                let patch = Patch::Apply(&object);
+                let result = scope.write(|| api.patch(name, &params, &patch)).await;
+                if result.is_ok() {
+                    track_rollout_if_requested(&app_handle, client, namespace, $resource_name, name, rollout_tracking_id);
+                }
+                log_resource_operation($resource_name, namespace, name, "apply", result).await
+            }
+        };
+    }
+
+    // Implement server-side apply operations for various resources
+    impl_apply_resource!(apply_pod, Pod, "pod");
+    impl_apply_resource!(apply_deployment, Deployment, "deployment");
+    impl_apply_resource!(apply_job, Job, "job");
+    impl_apply_resource!(apply_cronjob, CronJob, "cronjob");
+    impl_apply_resource!(apply_configmap, ConfigMap, "configmap");
+    impl_apply_resource!(apply_secret, Secret, "secret");
+    impl_apply_resource!(apply_service, Service, "service");
+    impl_apply_resource!(apply_ingress, Ingress, "ingress");
+    impl_apply_resource!(apply_persistentvolumeclaim, PersistentVolumeClaim, "persistent volume claim");
+
     #[tauri::command]
     pub async fn get_core_api_versions(
         context: &str,
+        options: Option<RequestOptions>,
     ) -> Result<Vec<String>, SerializableKubeError> {
         debug!("Fetching core API versions for context {}", context);
-        let client = client_with_context(context).await?;
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
 
-        let versions = client.list_core_api_versions().await.map_err(|err| {
-            error!("Failed to list core API versions: {}", err);
-            SerializableKubeError::from(err)
-        })?;
+        let versions = scope.read(|| client.list_core_api_versions())
+            .await
+            .inspect_err(|err| {
+                error!("Failed to list core API versions: {}", err.message);
+            })?;
 
         info!("Found {} core API versions", versions.versions.len());
         trace!("Available core API versions: {:?}", versions.versions);
@@ -649,28 +1659,36 @@ pub mod client {
     pub async fn get_core_api_resources(
         context: &str,
         core_api_version: &str,
+        options: Option<RequestOptions>,
     ) -> Result<Vec<APIResource>, SerializableKubeError> {
         debug!("Fetching core API resources for version {} in context {}", core_api_version, context);
-        let client = client_with_context(context).await?;
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
 
-        let resources = client.list_core_api_resources(core_api_version).await.map_err(|err| {
-            error!("Failed to list core API resources for version {}: {}", core_api_version, err);
-            SerializableKubeError::from(err)
-        })?;
+        let resources = scope.read(|| client.list_core_api_resources(core_api_version))
+            .await
+            .inspect_err(|err| {
+                error!("Failed to list core API resources for version {}: {}", core_api_version, err.message);
+            })?;
 
         info!("Found {} core API resources for version {}", resources.resources.len(), core_api_version);
         Ok(resources.resources)
     }
 
     #[tauri::command]
-    pub async fn get_api_groups(context: &str) -> Result<Vec<APIGroup>, SerializableKubeError> {
+    pub async fn get_api_groups(
+        context: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<Vec<APIGroup>, SerializableKubeError> {
         debug!("Fetching API groups for context {}", context);
-        let client = client_with_context(context).await?;
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
 
-        let groups = client.list_api_groups().await.map_err(|err| {
-            error!("Failed to list API groups: {}", err);
-            SerializableKubeError::from(err)
-        })?;
+        let groups = scope.read(|| client.list_api_groups())
+            .await
+            .inspect_err(|err| {
+                error!("Failed to list API groups: {}", err.message);
+            })?;
 
         info!("Found {} API groups", groups.groups.len());
         Ok(groups.groups)
@@ -680,14 +1698,17 @@ pub mod client {
     pub async fn get_api_group_resources(
         context: &str,
         api_group_version: &str,
+        options: Option<RequestOptions>,
     ) -> Result<Vec<APIResource>, SerializableKubeError> {
         debug!("Fetching API resources for group version {} in context {}", api_group_version, context);
-        let client = client_with_context(context).await?;
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
 
-        let resources = client.list_api_group_resources(api_group_version).await.map_err(|err| {
-            error!("Failed to list API resources for group version {}: {}", api_group_version, err);
-            SerializableKubeError::from(err)
-        })?;
+        let resources = scope.read(|| client.list_api_group_resources(api_group_version))
+            .await
+            .inspect_err(|err| {
+                error!("Failed to list API resources for group version {}: {}", api_group_version, err.message);
+            })?;
 
         info!("Found {} API resources for group version {}", resources.resources.len(), api_group_version);
         Ok(resources.resources)
@@ -698,23 +1719,25 @@ pub mod client {
         context: &str,
         namespace: &str,
         name: &str,
+        options: Option<RequestOptions>,
     ) -> Result<Job, SerializableKubeError> {
         debug!("Triggering manual run of cronjob {}/{}", namespace, name);
-        let mut client = client_with_context(context).await?;
+        let scope = RequestScope::new(options.as_ref());
+        let mut client = scope.client(context).await?;
 
         let cronjob_api: Api<CronJob> = Api::namespaced(client.clone(), namespace);
-        let selected_cronjob = cronjob_api.get(name).await.map_err(|err| {
-            error!("Failed to get cronjob {}/{}: {}", namespace, name, err);
-            SerializableKubeError::from(err)
-        })?;
+        let selected_cronjob = scope.read(|| cronjob_api.get(name))
+            .await
+            .inspect_err(|err| {
+                error!("Failed to get cronjob {}/{}: {}", namespace, name, err.message);
+            })?;
 
         let Some(cronjob_spec) = selected_cronjob.spec else {
-            let err = SerializableKubeError {
-                message: format!("Cronjob {}/{} has no spec", namespace, name),
-                code: None,
-                reason: Some("InvalidCronjobSpec".to_string()),
-                details: None,
-            };
+            let err = SerializableKubeError::new(
+                ErrorKind::Invalid,
+                "InvalidCronjobSpec",
+                format!("Cronjob {}/{} has no spec", namespace, name),
+            );
             error!("{}", err.message);
             return Err(err);
         };
@@ -739,10 +1762,12 @@ pub mod client {
         client = cronjob_api.into_client();
         let job_api: Api<Job> = Api::namespaced(client, namespace);
 
-        let job = job_api.create(&PostParams::default(), &manual_job).await.map_err(|err| {
-            error!("Failed to create manual job {} from cronjob {}: {}", jobname, name, err);
-            SerializableKubeError::from(err)
-        })?;
+        let params = PostParams::default();
+        let job = scope.write(|| job_api.create(&params, &manual_job))
+            .await
+            .inspect_err(|err| {
+                error!("Failed to create manual job {} from cronjob {}: {}", jobname, name, err.message);
+            })?;
 
         info!("Successfully created manual job {} from cronjob {}", jobname, name);
         Ok(job)
@@ -762,4 +1787,4901 @@ pub mod client {
             Err(format!("kubectl failed: {}", String::from_utf8_lossy(&output.stderr)))
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::ListMeta;
+        use serde_json::json;
+
+        #[test]
+        fn parse_single_apply_conflict() {
+            let conflicts = parse_apply_conflicts(
+                r#"Apply failed with 1 conflict: conflict with "kubectl-edit" using apps/v1: .spec.replicas"#,
+            )
+            .unwrap();
+
+            assert_eq!(conflicts.len(), 1);
+            assert_eq!(conflicts[0].manager, "kubectl-edit");
+            assert_eq!(conflicts[0].api_version.as_deref(), Some("apps/v1"));
+            assert_eq!(conflicts[0].field, ".spec.replicas");
+        }
+
+        #[test]
+        fn parse_apply_conflicts_per_manager() {
+            let conflicts = parse_apply_conflicts(
+                "Apply failed with 3 conflicts: conflicts with \"helm\" using apps/v1:\n\
+                 - .spec.replicas\n\
+                 - .spec.template.spec.containers[name=\"app\"].image\n\
+                 conflicts with \"kubectl-client-side-apply\":\n\
+                 - .metadata.labels.app",
+            )
+            .unwrap();
+
+            let fields: Vec<_> = conflicts
+                .iter()
+                .map(|c| (c.manager.as_str(), c.api_version.as_deref(), c.field.as_str()))
+                .collect();
+            assert_eq!(
+                fields,
+                vec![
+                    ("helm", Some("apps/v1"), ".spec.replicas"),
+                    ("helm", Some("apps/v1"), ".spec.template.spec.containers[name=\"app\"].image"),
+                    ("kubectl-client-side-apply", None, ".metadata.labels.app"),
+                ]
+            );
+        }
+
+        #[test]
+        fn ignore_messages_of_other_errors() {
+            assert!(parse_apply_conflicts("deployments.apps \"web\" not found").is_none());
+        }
+
+        fn kubeconfig(context: &str, user: &str, token: &str) -> Kubeconfig {
+            Kubeconfig::from_yaml(&format!(
+                "apiVersion: v1
+kind: Config
+clusters:
+- name: {context}
+  cluster:
+    server: https://{context}.example.com
+contexts:
+- name: {context}
+  context:
+    cluster: {context}
+    user: {user}
+users:
+- name: {user}
+  user:
+    token: {token}
+"
+            ))
+            .unwrap()
+        }
+
+        fn context_token(config: &Kubeconfig, context: &str) -> String {
+            let user = &config
+                .contexts
+                .iter()
+                .find(|named| named.name == context)
+                .and_then(|named| named.context.as_ref())
+                .unwrap()
+                .user;
+            let auth_info = config.auth_infos.iter().find(|named| named.name == *user).unwrap();
+            serde_json::to_value(auth_info).unwrap()["user"]["token"]
+                .as_str()
+                .unwrap()
+                .to_string()
+        }
+
+        #[test]
+        fn resolve_users_from_the_first_file_that_defines_them() {
+            let configs = vec![
+                (PathBuf::from("staging"), kubeconfig("staging", "admin", "staging-token")),
+                (PathBuf::from("production"), kubeconfig("production", "admin", "production-token")),
+            ];
+
+            // Same as `kubectl --context production` with KUBECONFIG=staging:production
+            let merged = merge_kubeconfigs(&configs).unwrap();
+            assert_eq!(context_token(&merged, "staging"), "staging-token");
+            assert_eq!(context_token(&merged, "production"), "staging-token");
+        }
+
+        fn temp_dir(name: &str) -> PathBuf {
+            let dir = env::temp_dir().join(format!("jet-pilot-{}-{}", name, uuid::Uuid::new_v4()));
+            fs::create_dir_all(&dir).unwrap();
+            dir
+        }
+
+        #[test]
+        fn resolve_listed_files_in_order() {
+            let dir = temp_dir("paths");
+            for name in ["a", "b"] {
+                fs::write(dir.join(name), "").unwrap();
+            }
+            let list = env::join_paths([dir.join("b"), dir.join("missing"), dir.join("a")]).unwrap();
+            let source = KubeconfigSource::Paths {
+                paths: vec![list.to_string_lossy().into_owned(), dir.join("b").to_string_lossy().into_owned()],
+            };
+
+            let files = kubeconfig_files(&source).unwrap();
+            assert_eq!(files, vec![dir.join("b"), dir.join("a")]);
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn resolve_directory_files_by_name_without_hidden_files() {
+            let dir = temp_dir("directory");
+            for name in ["prod.yaml", ".DS_Store", "dev.yaml"] {
+                fs::write(dir.join(name), "").unwrap();
+            }
+            fs::create_dir(dir.join("archive")).unwrap();
+
+            let source = KubeconfigSource::Directory {
+                path: dir.to_string_lossy().into_owned(),
+            };
+            let files = kubeconfig_files(&source).unwrap();
+            assert_eq!(files, vec![dir.join("dev.yaml"), dir.join("prod.yaml")]);
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[derive(Debug)]
+        struct ChainedError {
+            message: &'static str,
+            source: Option<Box<ChainedError>>,
+        }
+
+        impl std::fmt::Display for ChainedError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.message)
+            }
+        }
+
+        impl StdError for ChainedError {
+            fn source(&self) -> Option<&(dyn StdError + 'static)> {
+                self.source.as_deref().map(|source| source as &(dyn StdError + 'static))
+            }
+        }
+
+        fn chain(messages: &[&'static str]) -> ChainedError {
+            let mut error: Option<ChainedError> = None;
+            for message in messages.iter().rev() {
+                error = Some(ChainedError {
+                    message,
+                    source: error.map(Box::new),
+                });
+            }
+            error.unwrap()
+        }
+
+        fn api_error(code: u16) -> Error {
+            Error::Api(kube::core::ErrorResponse {
+                status: "Failure".to_string(),
+                message: String::new(),
+                reason: String::new(),
+                code,
+            })
+        }
+
+        #[test]
+        fn list_causes_without_repeating_messages() {
+            let error = chain(&[
+                "error trying to connect: connection refused",
+                "connection refused",
+                "os error 111",
+            ]);
+            assert_eq!(error_causes(&error), vec!["os error 111"]);
+
+            let error = chain(&["request failed", "invalid peer certificate: UnknownIssuer"]);
+            assert_eq!(error_causes(&error), vec!["invalid peer certificate: UnknownIssuer"]);
+        }
+
+        #[test]
+        fn classify_api_errors_by_status() {
+            let cases = [
+                (401, ErrorKind::Auth),
+                (403, ErrorKind::Forbidden),
+                (404, ErrorKind::NotFound),
+                (409, ErrorKind::Conflict),
+                (422, ErrorKind::Invalid),
+                (429, ErrorKind::TooManyRequests),
+                (504, ErrorKind::Timeout),
+                (503, ErrorKind::ServerError),
+            ];
+            for (code, kind) in cases {
+                assert_eq!(ErrorKind::of(&api_error(code)), kind, "status {}", code);
+            }
+        }
+
+        #[test]
+        fn classify_connection_errors_by_their_causes() {
+            let tls = Error::Service(Box::new(chain(&[
+                "client error (Connect)",
+                "invalid peer certificate: UnknownIssuer",
+            ])));
+            assert_eq!(ErrorKind::of(&tls), ErrorKind::Tls);
+
+            let timeout = Error::ReadEvents(std::io::Error::new(std::io::ErrorKind::TimedOut, "operation timed out"));
+            assert_eq!(ErrorKind::of(&timeout), ErrorKind::Timeout);
+
+            let refused = Error::Service(Box::new(chain(&["client error (Connect)", "connection refused"])));
+            assert_eq!(ErrorKind::of(&refused), ErrorKind::Network);
+            assert!(ErrorKind::of(&refused).is_retryable());
+        }
+
+        #[test]
+        fn classify_errors_raised_by_the_client() {
+            assert_eq!(ErrorKind::of(&Error::TlsRequired), ErrorKind::Tls);
+            assert_eq!(ErrorKind::of(&Error::Auth(AuthError::ExecPluginFailed)), ErrorKind::ExecPlugin);
+            assert_eq!(
+                ErrorKind::of(&Error::Discovery(DiscoveryError::MissingKind("Rollout".to_string()))),
+                ErrorKind::Unsupported
+            );
+            assert_eq!(
+                ErrorKind::of(&Error::Discovery(DiscoveryError::InvalidGroupVersion("a/b/c".to_string()))),
+                ErrorKind::Invalid
+            );
+            assert_eq!(ErrorKind::of(&Error::LinesCodecMaxLineLengthExceeded), ErrorKind::Unknown);
+            assert!(!ErrorKind::Forbidden.is_retryable());
+        }
+
+        #[test]
+        fn fail_without_any_kubeconfig() {
+            let source = KubeconfigSource::Paths {
+                paths: vec!["/nonexistent/kubeconfig".to_string()],
+            };
+            assert_eq!(kubeconfig_files(&source).unwrap_err().reason.as_deref(), Some("NoKubeconfig"));
+        }
+
+        #[test]
+        fn project_columns_through_objects_and_arrays() {
+            let object = json!({
+                "apiVersion": "v1",
+                "kind": "Pod",
+                "metadata": {
+                    "name": "web",
+                    "managedFields": [{"manager": "kubectl"}],
+                    "annotations": {LAST_APPLIED_ANNOTATION: "{}", "team": "a"},
+                },
+                "spec": {
+                    "nodeName": "node-1",
+                    "containers": [{"name": "app", "image": "app:1"}, {"name": "sidecar", "image": "proxy:2"}],
+                },
+                "status": {"phase": "Running", "podIP": "10.0.0.1"},
+            });
+
+            let columns = vec![
+                "status.phase".to_string(),
+                "spec.containers.image".to_string(),
+                "spec.missing.field".to_string(),
+            ];
+            assert_eq!(
+                project(&object, &columns),
+                json!({
+                    "apiVersion": "v1",
+                    "kind": "Pod",
+                    "metadata": {"name": "web", "annotations": {"team": "a"}},
+                    "spec": {"containers": [{"image": "app:1"}, {"image": "proxy:2"}]},
+                    "status": {"phase": "Running"},
+                })
+            );
+        }
+
+        #[test]
+        fn copy_paths_into_the_same_parent() {
+            let source = json!({"status": {"phase": "Running", "podIP": "10.0.0.1", "hostIP": "10.0.1.1"}});
+            let mut target = Map::new();
+            copy_path(&source, &mut target, &["status", "phase"]);
+            copy_path(&source, &mut target, &["status", "podIP"]);
+            copy_path(&source, &mut target, &["status", "phase", "deeper"]);
+
+            assert_eq!(Value::Object(target), json!({"status": {"phase": "Running", "podIP": "10.0.0.1"}}));
+        }
+
+        fn chunk(names: &[&str], continue_token: Option<&str>) -> ObjectList<ConfigMap> {
+            ObjectList {
+                types: Default::default(),
+                metadata: ListMeta {
+                    continue_: continue_token.map(String::from),
+                    resource_version: Some("42".to_string()),
+                    ..Default::default()
+                },
+                items: names
+                    .iter()
+                    .map(|name| ConfigMap {
+                        metadata: ObjectMeta {
+                            name: Some(name.to_string()),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                    .collect(),
+            }
+        }
+
+        fn page_names(result: ListResult<ConfigMap>) -> (Vec<String>, Option<String>) {
+            let ListResult::Page(page) = result else {
+                panic!("expected a page");
+            };
+            let names = page
+                .items
+                .iter()
+                .map(|item| item["metadata"]["name"].as_str().unwrap().to_string())
+                .collect();
+            (names, page.continue_token)
+        }
+
+        #[tokio::test]
+        async fn follow_continue_tokens_without_a_limit() {
+            let mut chunks = vec![chunk(&["a", "b"], Some("1")), chunk(&["c"], Some("2")), chunk(&["d"], Some(""))];
+            chunks.reverse();
+            let mut requested = Vec::new();
+            let page = PageOptions {
+                columns: Some(vec!["data".to_string()]),
+                ..Default::default()
+            };
+
+            let result = collect_pages(ListParams::default(), &page, |params| {
+                requested.push((params.limit, params.continue_token));
+                let chunk = chunks.pop().unwrap();
+                async move { Ok(chunk) }
+            })
+            .await
+            .unwrap();
+
+            assert_eq!(page_names(result), (vec!["a", "b", "c", "d"].into_iter().map(String::from).collect(), None));
+            assert_eq!(
+                requested,
+                vec![
+                    (Some(LIST_CHUNK_SIZE), None),
+                    (Some(LIST_CHUNK_SIZE), Some("1".to_string())),
+                    (Some(LIST_CHUNK_SIZE), Some("2".to_string())),
+                ]
+            );
+        }
+
+        #[tokio::test]
+        async fn return_one_page_with_a_limit() {
+            let mut requested = Vec::new();
+            let page = PageOptions {
+                limit: Some(2),
+                continue_token: Some("1".to_string()),
+                columns: None,
+            };
+
+            let result = collect_pages(ListParams::default(), &page, |params| {
+                requested.push((params.limit, params.continue_token));
+                async { Ok(chunk(&["c", "d"], Some("2"))) }
+            })
+            .await
+            .unwrap();
+
+            assert_eq!(page_names(result), (vec!["c".to_string(), "d".to_string()], Some("2".to_string())));
+            assert_eq!(requested, vec![(Some(2), Some("1".to_string()))]);
+        }
+
+        #[tokio::test]
+        async fn require_a_limit_or_columns() {
+            let result = collect_pages(ListParams::default(), &PageOptions::default(), |_| async {
+                Ok(chunk(&["a"], None))
+            })
+            .await;
+
+            assert_eq!(result.err().and_then(|err| err.reason).as_deref(), Some("LimitRequired"));
+        }
+
+        #[test]
+        fn classify_an_expired_continue_token() {
+            assert_eq!(ErrorKind::from_status(410), ErrorKind::Expired);
+            assert!(!ErrorKind::Expired.is_retryable());
+        }
+
+        fn scope_with_id(request_id: &str) -> RequestScope {
+            RequestScope::new(Some(&RequestOptions {
+                request_id: Some(request_id.to_string()),
+                ..Default::default()
+            }))
+        }
+
+        #[tokio::test]
+        async fn cancel_a_request_that_is_still_connecting() {
+            let scope = scope_with_id("connecting");
+            let connecting = scope.connect(std::future::pending());
+            let (result, cancelled) = tokio::join!(connecting, cancel_request("connecting"));
+
+            assert!(cancelled);
+            assert_eq!(result.err().map(|err| err.kind), Some(ErrorKind::Cancelled));
+        }
+
+        #[tokio::test]
+        async fn apply_the_deadline_to_connecting() {
+            let scope = RequestScope::new(Some(&RequestOptions {
+                timeout_seconds: Some(0),
+                ..Default::default()
+            }));
+
+            let result = scope.connect(std::future::pending()).await;
+            assert_eq!(result.err().map(|err| err.kind), Some(ErrorKind::Timeout));
+        }
+
+        #[tokio::test]
+        async fn supersede_a_request_between_calls() {
+            let older = scope_with_id("superseded");
+            assert_eq!(older.read(|| async { Ok::<_, SerializableKubeError>(1) }).await.unwrap(), 1);
+
+            let newer = scope_with_id("superseded");
+            let result = older.read(|| async { Ok::<_, SerializableKubeError>(2) }).await;
+            assert_eq!(result.unwrap_err().kind, ErrorKind::Cancelled);
+            assert_eq!(newer.read(|| async { Ok::<_, SerializableKubeError>(3) }).await.unwrap(), 3);
+        }
+    }
+}
+
+pub mod auth {
+    use super::client::{merge_kubeconfigs, read_kubeconfigs, ErrorKind, SerializableKubeError};
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+    use chrono::{DateTime, TimeZone, Utc};
+    use kube::config::{AuthInfo, ExecConfig, Kubeconfig};
+    use secrecy::ExposeSecret;
+    use serde::Serialize;
+    use serde_json::{json, Value};
+    use std::io;
+    use std::path::Path;
+    use std::process::Stdio;
+    use std::time::Duration;
+    use tokio::process::Command;
+    use tokio::time::timeout;
+    use tracing::{debug, info, warn};
+
+    const EXEC_PLUGIN_TIMEOUT: Duration = Duration::from_secs(20);
+
+    // Lowercased fragments of the messages plugins print when they need a person at the keyboard
+    const LOGIN_REQUIRED_MARKERS: [&str; 14] = [
+        "sso session",
+        "error loading sso token",
+        "token has expired",
+        "aws sso login",
+        "gcloud auth login",
+        "reauthentication",
+        "az login",
+        "aadsts",
+        "devicelogin",
+        "device code",
+        "use a web browser",
+        "please login",
+        "please log in",
+        "not logged in",
+    ];
+
+    #[derive(Clone, Debug, Serialize)]
+    #[serde(tag = "type")]
+    pub enum AuthMethod {
+        None,
+        Token { from_file: bool },
+        ClientCertificate,
+        Basic,
+        Exec {
+            command: String,
+            args: Vec<String>,
+            api_version: Option<String>,
+        },
+        AuthProvider { name: String },
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize)]
+    pub enum AuthState {
+        Valid,
+        Expired,
+        LoginRequired,
+        MissingBinary,
+        Failed,
+        Timeout,
+    }
+
+    #[derive(Clone, Debug, Serialize)]
+    pub struct AuthDiagnosis {
+        pub(crate) context: String,
+        pub(crate) user: Option<String>,
+        pub(crate) method: AuthMethod,
+        pub(crate) state: AuthState,
+        /// Machine-readable cause, e.g. `ExecPluginNotFound`, matching the `reason` of errors.
+        pub(crate) reason: Option<String>,
+        pub(crate) message: Option<String>,
+        pub(crate) hint: Option<String>,
+        pub(crate) expires_at: Option<String>,
+        pub(crate) stderr: Option<String>,
+    }
+
+    impl AuthDiagnosis {
+        fn new(context: &str, user: Option<String>, method: AuthMethod) -> Self {
+            AuthDiagnosis {
+                context: context.to_string(),
+                user,
+                method,
+                state: AuthState::Valid,
+                reason: None,
+                message: None,
+                hint: None,
+                expires_at: None,
+                stderr: None,
+            }
+        }
+
+        fn fail(&mut self, state: AuthState, reason: &str, message: String, hint: Option<String>) {
+            self.state = state;
+            self.reason = Some(reason.to_string());
+            self.message = Some(message);
+            self.hint = hint;
+        }
+
+        /// Records when the credential expires, marking it expired when that has passed.
+        fn expires(&mut self, expiry: DateTime<Utc>, hint: Option<String>) {
+            self.expires_at = Some(expiry.to_rfc3339());
+            if expiry <= Utc::now() {
+                self.fail(
+                    AuthState::Expired,
+                    "TokenExpired",
+                    format!("The credential expired at {}", expiry.to_rfc3339()),
+                    hint,
+                );
+            }
+        }
+    }
+
+    pub(crate) fn classify(auth_info: &AuthInfo) -> AuthMethod {
+        if let Some(exec) = &auth_info.exec {
+            AuthMethod::Exec {
+                command: exec.command.clone().unwrap_or_default(),
+                args: exec.args.clone().unwrap_or_default(),
+                api_version: exec.api_version.clone(),
+            }
+        } else if let Some(provider) = &auth_info.auth_provider {
+            AuthMethod::AuthProvider {
+                name: provider.name.clone(),
+            }
+        } else if auth_info.token.is_some() {
+            AuthMethod::Token { from_file: false }
+        } else if auth_info.token_file.is_some() {
+            AuthMethod::Token { from_file: true }
+        } else if auth_info.client_certificate.is_some() || auth_info.client_certificate_data.is_some() {
+            AuthMethod::ClientCertificate
+        } else if auth_info.username.is_some() {
+            AuthMethod::Basic
+        } else {
+            AuthMethod::None
+        }
+    }
+
+    /// Reads the `exp` claim of a JWT without verifying it. Opaque tokens have no expiry.
+    fn jwt_expiry(token: &str) -> Option<DateTime<Utc>> {
+        let payload = token.split('.').nth(1)?;
+        // Tokens are unpadded, but tolerate issuers that pad them anyway
+        let payload = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+        let claims: Value = serde_json::from_slice(&payload).ok()?;
+        Utc.timestamp_opt(claims.get("exp")?.as_i64()?, 0).single()
+    }
+
+    fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(value)
+            .ok()
+            .map(|timestamp| timestamp.with_timezone(&Utc))
+    }
+
+    fn exec_env(exec: &ExecConfig, name: &str) -> Option<String> {
+        exec.env
+            .iter()
+            .flatten()
+            .find(|env| env.get("name").map(String::as_str) == Some(name))
+            .and_then(|env| env.get("value").cloned())
+    }
+
+    fn exec_arg(exec: &ExecConfig, flag: &str) -> Option<String> {
+        let args = exec.args.as_deref().unwrap_or_default();
+        args.iter()
+            .position(|arg| arg == flag)
+            .and_then(|index| args.get(index + 1).cloned())
+    }
+
+    fn command_name(exec: &ExecConfig) -> String {
+        let command = exec.command.as_deref().unwrap_or_default();
+        Path::new(command)
+            .file_stem()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| command.to_string())
+    }
+
+    fn login_hint(exec: &ExecConfig) -> String {
+        match command_name(exec).as_str() {
+            "aws" | "aws-iam-authenticator" => {
+                match exec_env(exec, "AWS_PROFILE").or_else(|| exec_arg(exec, "--profile")) {
+                    Some(profile) => format!("Run `aws sso login --profile {}`", profile),
+                    None => "Run `aws sso login` or refresh your AWS credentials".to_string(),
+                }
+            }
+            "gke-gcloud-auth-plugin" | "gcloud" => "Run `gcloud auth login`".to_string(),
+            "kubelogin" => "Run `az login`, or `kubelogin remove-tokens` to clear a stale cache".to_string(),
+            "oci" => "Run `oci session authenticate`".to_string(),
+            "doctl" => "Run `doctl auth init`".to_string(),
+            command => format!("Run `{}` in a terminal and complete the login it asks for", command),
+        }
+    }
+
+    fn install_hint(exec: &ExecConfig) -> String {
+        match command_name(exec).as_str() {
+            "aws" => "Install the AWS CLI and make sure it is on your PATH".to_string(),
+            "gke-gcloud-auth-plugin" => {
+                "Run `gcloud components install gke-gcloud-auth-plugin`".to_string()
+            }
+            "kubelogin" => "Install kubelogin with `az aks install-cli`".to_string(),
+            command => format!("Install `{}` and make sure it is on your PATH", command),
+        }
+    }
+
+    /// Runs the exec plugin the way the client would, but non-interactively and with its
+    /// output captured, so failures can be told apart.
+    async fn diagnose_exec(diagnosis: &mut AuthDiagnosis, exec: &ExecConfig) {
+        let Some(command) = exec.command.as_deref().filter(|command| !command.is_empty()) else {
+            diagnosis.fail(
+                AuthState::Failed,
+                "ExecPluginMisconfigured",
+                "The exec plugin has no command".to_string(),
+                None,
+            );
+            return;
+        };
+
+        let exec_info = json!({
+            "apiVersion": exec.api_version.as_deref().unwrap_or("client.authentication.k8s.io/v1beta1"),
+            "kind": "ExecCredential",
+            "spec": { "interactive": false },
+        });
+
+        let mut process = Command::new(command);
+        process
+            .args(exec.args.as_deref().unwrap_or_default())
+            .env("KUBERNETES_EXEC_INFO", exec_info.to_string())
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        for env in exec.env.iter().flatten() {
+            if let (Some(name), Some(value)) = (env.get("name"), env.get("value")) {
+                process.env(name, value);
+            }
+        }
+
+        debug!("Running exec plugin {} for context {}", command, diagnosis.context);
+        let output = match timeout(EXEC_PLUGIN_TIMEOUT, process.output()).await {
+            Ok(Ok(output)) => output,
+            Ok(Err(err)) if err.kind() == io::ErrorKind::NotFound => {
+                diagnosis.fail(
+                    AuthState::MissingBinary,
+                    "ExecPluginNotFound",
+                    format!("The exec plugin `{}` was not found", command),
+                    Some(install_hint(exec)),
+                );
+                return;
+            }
+            Ok(Err(err)) => {
+                diagnosis.fail(
+                    AuthState::Failed,
+                    "ExecPluginFailed",
+                    format!("Failed to run `{}`: {}", command, err),
+                    None,
+                );
+                return;
+            }
+            Err(_) => {
+                // Plugins that wait on a browser or device code never return on their own
+                diagnosis.fail(
+                    AuthState::Timeout,
+                    "ExecPluginTimeout",
+                    format!("`{}` did not finish within {}s", command, EXEC_PLUGIN_TIMEOUT.as_secs()),
+                    Some(login_hint(exec)),
+                );
+                return;
+            }
+        };
+
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        if !stderr.is_empty() {
+            diagnosis.stderr = Some(stderr.clone());
+        }
+
+        if !output.status.success() {
+            let lowercase = stderr.to_lowercase();
+            if LOGIN_REQUIRED_MARKERS.iter().any(|marker| lowercase.contains(marker)) {
+                diagnosis.fail(
+                    AuthState::LoginRequired,
+                    "ExecPluginLoginRequired",
+                    format!("`{}` needs an interactive login", command),
+                    Some(login_hint(exec)),
+                );
+            } else {
+                diagnosis.fail(
+                    AuthState::Failed,
+                    "ExecPluginFailed",
+                    format!("`{}` exited with {}", command, output.status),
+                    Some(format!("Run `{}` in a terminal to see the full error", command_name(exec))),
+                );
+            }
+            return;
+        }
+
+        let credential: Value = match serde_json::from_slice(&output.stdout) {
+            Ok(credential) => credential,
+            Err(err) => {
+                diagnosis.fail(
+                    AuthState::Failed,
+                    "ExecPluginInvalidOutput",
+                    format!("`{}` did not print an ExecCredential: {}", command, err),
+                    None,
+                );
+                return;
+            }
+        };
+
+        let status = &credential["status"];
+        let expiry = status["expirationTimestamp"]
+            .as_str()
+            .and_then(parse_timestamp)
+            .or_else(|| status["token"].as_str().and_then(jwt_expiry));
+        if let Some(expiry) = expiry {
+            diagnosis.expires(expiry, Some(login_hint(exec)));
+        }
+    }
+
+    fn diagnose_auth_provider(diagnosis: &mut AuthDiagnosis, auth_info: &AuthInfo) {
+        let Some(provider) = &auth_info.auth_provider else {
+            return;
+        };
+
+        let expiry = match provider.name.as_str() {
+            "oidc" => provider.config.get("id-token").and_then(|token| jwt_expiry(token)),
+            _ => provider
+                .config
+                .get("expiry")
+                .and_then(|expiry| parse_timestamp(expiry)),
+        };
+        // Refreshable providers renew expired tokens themselves, so only report the expiry
+        let refreshable = provider.config.contains_key("refresh-token")
+            || provider.config.contains_key("cmd-path");
+        match expiry {
+            Some(expiry) if refreshable => diagnosis.expires_at = Some(expiry.to_rfc3339()),
+            Some(expiry) => diagnosis.expires(
+                expiry,
+                Some(format!("Log in again with your {} provider", provider.name)),
+            ),
+            None => {}
+        }
+    }
+
+    fn diagnose_token(diagnosis: &mut AuthDiagnosis, auth_info: &AuthInfo) {
+        let token = match (&auth_info.token, &auth_info.token_file) {
+            (Some(token), _) => token.expose_secret().to_string(),
+            (None, Some(file)) => match std::fs::read_to_string(file) {
+                Ok(token) => token.trim().to_string(),
+                Err(err) => {
+                    diagnosis.fail(
+                        AuthState::Failed,
+                        "TokenFileUnreadable",
+                        format!("Failed to read token file {}: {}", file, err),
+                        None,
+                    );
+                    return;
+                }
+            },
+            (None, None) => return,
+        };
+
+        if let Some(expiry) = jwt_expiry(&token) {
+            diagnosis.expires(expiry, Some("Replace the token in your kubeconfig".to_string()));
+        }
+    }
+
+    pub(crate) async fn diagnose_auth(
+        config: &Kubeconfig,
+        context: &str,
+    ) -> Result<AuthDiagnosis, SerializableKubeError> {
+        let user = config
+            .contexts
+            .iter()
+            .find(|named| named.name == context)
+            .ok_or_else(|| SerializableKubeError::new(
+                ErrorKind::Kubeconfig,
+                "ContextNotFound",
+                format!("Context {} not found", context),
+            ))?
+            .context
+            .as_ref()
+            .map(|context| context.user.clone());
+
+        let auth_info = user
+            .as_ref()
+            .and_then(|user| config.auth_infos.iter().find(|named| &named.name == user))
+            .and_then(|named| named.auth_info.clone())
+            .unwrap_or_default();
+
+        let method = classify(&auth_info);
+        let mut diagnosis = AuthDiagnosis::new(context, user, method.clone());
+        match method {
+            AuthMethod::Exec { .. } => {
+                if let Some(exec) = &auth_info.exec {
+                    diagnose_exec(&mut diagnosis, exec).await;
+                }
+            }
+            AuthMethod::AuthProvider { .. } => diagnose_auth_provider(&mut diagnosis, &auth_info),
+            AuthMethod::Token { .. } => diagnose_token(&mut diagnosis, &auth_info),
+            AuthMethod::ClientCertificate | AuthMethod::Basic | AuthMethod::None => {}
+        }
+
+        if diagnosis.state != AuthState::Valid {
+            warn!(
+                "Authentication for context {} is not usable: {:?}",
+                context, diagnosis.reason
+            );
+        }
+        Ok(diagnosis)
+    }
+
+    #[tauri::command]
+    pub async fn diagnose_context_auth(
+        context: &str,
+        kube_config: Option<String>,
+    ) -> Result<AuthDiagnosis, SerializableKubeError> {
+        debug!("Diagnosing authentication for context {}", context);
+        let config = match kube_config.filter(|path| !path.is_empty()) {
+            Some(path) => Kubeconfig::read_from(path).map_err(SerializableKubeError::from)?,
+            None => merge_kubeconfigs(&read_kubeconfigs()?)?,
+        };
+
+        let diagnosis = diagnose_auth(&config, context).await?;
+        info!("Authentication for context {} is {:?}", context, diagnosis.state);
+        Ok(diagnosis)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn jwt(claims: Value) -> String {
+            let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"RS256","typ":"JWT"}"#);
+            let payload = URL_SAFE_NO_PAD.encode(claims.to_string());
+            format!("{}.{}.signature", header, payload)
+        }
+
+        #[test]
+        fn read_the_expiry_of_a_jwt() {
+            let token = jwt(json!({ "sub": "system:serviceaccount:default:ci", "exp": 1_700_000_000 }));
+            assert_eq!(jwt_expiry(&token), Utc.timestamp_opt(1_700_000_000, 0).single());
+        }
+
+        #[test]
+        fn accept_padded_payloads() {
+            let token = "header.eyJleHAiOjE3MDAwMDAwMDAsInN1YiI6ImNpIn0=.signature";
+            assert_eq!(jwt_expiry(token), Utc.timestamp_opt(1_700_000_000, 0).single());
+        }
+
+        #[test]
+        fn opaque_tokens_have_no_expiry() {
+            assert_eq!(jwt_expiry("0123456789abcdef"), None);
+            assert_eq!(jwt_expiry("not.base64!.token"), None);
+            assert_eq!(jwt_expiry(&jwt(json!({ "sub": "admin" }))), None);
+        }
+
+        fn token_user(token: &str) -> AuthInfo {
+            serde_json::from_value(json!({ "token": token })).unwrap()
+        }
+
+        #[test]
+        fn report_an_expired_token() {
+            let mut diagnosis = AuthDiagnosis::new("dev", None, AuthMethod::Token { from_file: false });
+            diagnose_token(&mut diagnosis, &token_user(&jwt(json!({ "exp": 1_000_000_000 }))));
+
+            assert_eq!(diagnosis.state, AuthState::Expired);
+            assert_eq!(diagnosis.reason.as_deref(), Some("TokenExpired"));
+            assert_eq!(diagnosis.expires_at.as_deref(), Some("2001-09-09T01:46:40+00:00"));
+        }
+
+        #[test]
+        fn accept_a_valid_token() {
+            let expiry = (Utc::now() + chrono::Duration::hours(1)).timestamp();
+            let mut diagnosis = AuthDiagnosis::new("dev", None, AuthMethod::Token { from_file: false });
+            diagnose_token(&mut diagnosis, &token_user(&jwt(json!({ "exp": expiry }))));
+
+            assert_eq!(diagnosis.state, AuthState::Valid);
+            assert!(diagnosis.expires_at.is_some());
+        }
+    }
+}
+
+pub mod probe {
+    use super::auth::diagnose_auth;
+    use super::client::{
+        build_client, current_kubeconfig_files, error_causes, merge_kubeconfigs, read_kubeconfigs,
+        ErrorKind, SerializableKubeError,
+    };
+    use futures::future::join_all;
+    use kube::config::Kubeconfig;
+    use kube::{Client, Error};
+    use serde::Serialize;
+    use std::time::{Duration, Instant};
+    use tokio::time::timeout;
+    use tracing::{debug, info};
+
+    const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+    #[derive(Clone, Debug, PartialEq, Serialize)]
+    pub enum ProbeAuthState {
+        Authenticated,
+        Unauthenticated,
+        Unknown,
+    }
+
+    #[derive(Clone, Debug, Serialize)]
+    pub struct ContextProbe {
+        context: String,
+        reachable: bool,
+        /// Whether `/readyz` reported the API server ready, when it could be asked.
+        ready: Option<bool>,
+        server_version: Option<String>,
+        latency_ms: Option<u64>,
+        auth: ProbeAuthState,
+        auth_reason: Option<String>,
+        auth_hint: Option<String>,
+        tls_error: Option<String>,
+        error: Option<String>,
+    }
+
+    impl ContextProbe {
+        fn new(context: &str) -> Self {
+            ContextProbe {
+                context: context.to_string(),
+                reachable: false,
+                ready: None,
+                server_version: None,
+                latency_ms: None,
+                auth: ProbeAuthState::Unknown,
+                auth_reason: None,
+                auth_hint: None,
+                tls_error: None,
+                error: None,
+            }
+        }
+    }
+
+    /// Joins an error with its sources, the same way they appear in `SerializableKubeError.causes`.
+    fn describe(error: &Error) -> String {
+        let mut messages = vec![error.to_string()];
+        messages.extend(error_causes(error));
+        messages.join(": ")
+    }
+
+    fn record_error(probe: &mut ContextProbe, error: &Error) {
+        let description = describe(error);
+        match error {
+            Error::Api(response) if response.code == 401 => {
+                probe.reachable = true;
+                probe.auth = ProbeAuthState::Unauthenticated;
+                probe.auth_reason = Some(response.reason.clone());
+            }
+            Error::Api(_) => probe.reachable = true,
+            Error::Auth(_) => probe.auth = ProbeAuthState::Unauthenticated,
+            _ if ErrorKind::of(error) == ErrorKind::Tls => probe.tls_error = Some(description.clone()),
+            _ => {}
+        }
+        probe.error = Some(description);
+    }
+
+    async fn probe_client(probe: &mut ContextProbe, client: Client) {
+        let started = Instant::now();
+        match client.apiserver_version().await {
+            Ok(version) => {
+                probe.latency_ms = Some(started.elapsed().as_millis() as u64);
+                probe.reachable = true;
+                probe.auth = ProbeAuthState::Authenticated;
+                probe.server_version = Some(version.git_version);
+            }
+            Err(err) => {
+                record_error(probe, &err);
+                return;
+            }
+        }
+
+        let request = http::Request::get("/readyz").body(Vec::new());
+        let Ok(request) = request else {
+            return;
+        };
+        probe.ready = match client.request_text(request).await {
+            Ok(body) => Some(body.trim() == "ok"),
+            // Forbidden only means the user may not ask, not that the server is unhealthy
+            Err(Error::Api(response)) if response.code == 401 || response.code == 403 => None,
+            Err(Error::Api(_)) => Some(false),
+            Err(err) => {
+                debug!("Failed to check readiness of context {}: {}", probe.context, err);
+                None
+            }
+        };
+    }
+
+    async fn probe_context(
+        config: &Kubeconfig,
+        kubeconfigs: &[String],
+        context: &str,
+        probe_timeout: Duration,
+    ) -> ContextProbe {
+        let mut probe = ContextProbe::new(context);
+
+        // Probing every context would otherwise fill the pool with clients nobody uses
+        let probed = timeout(probe_timeout, async {
+            match build_client(kubeconfigs, context).await {
+                Ok(client) => probe_client(&mut probe, client).await,
+                Err(err) => probe.error = Some(err.message),
+            }
+        })
+        .await;
+        if probed.is_err() {
+            probe.error = Some(format!("Timed out after {}s", probe_timeout.as_secs()));
+        }
+
+        // Explain why authentication failed, which usually is an exec plugin needing a login
+        if probe.auth == ProbeAuthState::Unauthenticated {
+            if let Ok(Ok(diagnosis)) = timeout(probe_timeout, diagnose_auth(config, context)).await {
+                probe.auth_reason = diagnosis.reason.or(probe.auth_reason);
+                probe.auth_hint = diagnosis.hint;
+            }
+        }
+        probe
+    }
+
+    #[tauri::command]
+    pub async fn probe_contexts(
+        timeout_seconds: Option<u64>,
+    ) -> Result<Vec<ContextProbe>, SerializableKubeError> {
+        let probe_timeout = timeout_seconds
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_PROBE_TIMEOUT);
+        let config = merge_kubeconfigs(&read_kubeconfigs()?)?;
+        let kubeconfigs = current_kubeconfig_files()?;
+        debug!("Probing {} contexts", config.contexts.len());
+
+        let probes = join_all(
+            config
+                .contexts
+                .iter()
+                .map(|context| probe_context(&config, &kubeconfigs, &context.name, probe_timeout)),
+        )
+        .await;
+
+        info!(
+            "Probed {} contexts, {} reachable",
+            probes.len(),
+            probes.iter().filter(|probe| probe.reachable).count()
+        );
+        Ok(probes)
+    }
+}
+
+pub mod kubeconfig_watch {
+    use super::client::{
+        client_kubeconfig, current_kubeconfig_source, default_kubeconfig_paths,
+        invalidate_cached_clients, reload_kubeconfigs, KubeconfigSource,
+    };
+    use kube::config::Kubeconfig;
+    use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+    use serde::Serialize;
+    use serde_json::{json, Value};
+    use std::collections::{BTreeSet, HashMap};
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use tauri::async_runtime::JoinHandle;
+    use tauri::Emitter;
+    use tokio::sync::mpsc;
+    use tokio::time::sleep;
+    use tracing::{debug, error, info, warn};
+
+    /// Tools like `aws eks update-kubeconfig` write through a temporary file and a rename,
+    /// which arrives as a burst of events. They are handled once the burst settles.
+    const SETTLE_DELAY: Duration = Duration::from_millis(300);
+
+    static KUBECONFIG_WATCHER: Mutex<Option<(RecommendedWatcher, JoinHandle<()>)>> = Mutex::new(None);
+
+    #[derive(Clone, Debug, Default, Serialize)]
+    pub struct KubeconfigChange {
+        added: Vec<String>,
+        removed: Vec<String>,
+        modified: Vec<String>,
+    }
+
+    impl KubeconfigChange {
+        fn is_empty(&self) -> bool {
+            self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+        }
+    }
+
+    /// Everything a client for each context is built from: the context itself together
+    /// with the cluster and user it points at in the kubeconfig from `client_kubeconfig`.
+    fn context_definitions(config: &Kubeconfig) -> HashMap<String, Value> {
+        config
+            .contexts
+            .iter()
+            .map(|named| {
+                let context = named.context.as_ref();
+                let cluster = context.and_then(|context| {
+                    config.clusters.iter().find(|cluster| cluster.name == context.cluster)
+                });
+                let user = context.and_then(|context| {
+                    config.auth_infos.iter().find(|user| user.name == context.user)
+                });
+                let definition = json!({ "context": context, "cluster": cluster, "user": user });
+                (named.name.clone(), definition)
+            })
+            .collect()
+    }
+
+    fn current_definitions() -> Option<HashMap<String, Value>> {
+        let configs = reload_kubeconfigs()
+            .map_err(|err| warn!("Failed to reload kubeconfigs: {}", err.message))
+            .ok()?;
+        let config = client_kubeconfig(&configs)
+            .map_err(|err| warn!("Failed to merge kubeconfigs: {}", err.message))
+            .ok()?;
+        Some(context_definitions(&config))
+    }
+
+    fn diff_definitions(old: &HashMap<String, Value>, new: &HashMap<String, Value>) -> KubeconfigChange {
+        let names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+        let mut change = KubeconfigChange::default();
+        for name in names {
+            match (old.get(name), new.get(name)) {
+                (None, Some(_)) => change.added.push(name.clone()),
+                (Some(_), None) => change.removed.push(name.clone()),
+                (Some(old), Some(new)) if old != new => change.modified.push(name.clone()),
+                _ => {}
+            }
+        }
+        change
+    }
+
+    fn expand_home(path: &Path) -> PathBuf {
+        let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"));
+        match (path.strip_prefix("~"), home) {
+            (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+            _ => path.to_path_buf(),
+        }
+    }
+
+    /// Makes a path absolute with its directory resolved, but keeps the file name itself
+    /// even when it is a symlink. Works for files that do not exist (yet).
+    fn resolve_directory(path: &Path) -> PathBuf {
+        let path = expand_home(path);
+        let path = match env::current_dir() {
+            Ok(current) if path.is_relative() => current.join(path),
+            _ => path,
+        };
+        match (path.parent(), path.file_name()) {
+            (Some(parent), Some(name)) => fs::canonicalize(parent)
+                .map(|parent| parent.join(name))
+                .unwrap_or(path),
+            _ => path,
+        }
+    }
+
+    /// The forms a path can take in watcher events, which are absolute and have their
+    /// directories resolved (on macOS `/var/...` arrives as `/private/var/...`): the path
+    /// itself and, for a symlink, the file it points at.
+    fn event_paths(path: &Path) -> Vec<PathBuf> {
+        let link = resolve_directory(path);
+        match fs::canonicalize(&link) {
+            Ok(target) if target != link => vec![link, target],
+            _ => vec![link],
+        }
+    }
+
+    /// The directories to watch and, unless a whole directory is the source, the files in
+    /// them that matter. Parent directories are watched so files that are replaced by a
+    /// rename or created later are still picked up, and for symlinks the directory of the
+    /// target is watched as well.
+    fn watch_targets(source: &KubeconfigSource) -> (BTreeSet<PathBuf>, Option<Vec<PathBuf>>) {
+        let files: Vec<PathBuf> = match source {
+            KubeconfigSource::Directory { path } => {
+                let directory = fs::canonicalize(expand_home(Path::new(path)))
+                    .unwrap_or_else(|_| PathBuf::from(path));
+                return (BTreeSet::from([directory]), None);
+            }
+            KubeconfigSource::Default => default_kubeconfig_paths(),
+            KubeconfigSource::Paths { paths } => paths
+                .iter()
+                .flat_map(std::env::split_paths)
+                .filter(|path| !path.as_os_str().is_empty())
+                .collect(),
+        };
+        let files: Vec<PathBuf> = files.iter().flat_map(|file| event_paths(file)).collect();
+
+        let directories = files
+            .iter()
+            .filter_map(|file| file.parent().map(PathBuf::from))
+            .filter(|directory| directory.is_dir())
+            .collect();
+        (directories, Some(files))
+    }
+
+    async fn handle_changes(
+        app_handle: tauri::AppHandle,
+        mut changes: mpsc::UnboundedReceiver<()>,
+        mut definitions: HashMap<String, Value>,
+    ) {
+        while changes.recv().await.is_some() {
+            sleep(SETTLE_DELAY).await;
+            while changes.try_recv().is_ok() {}
+
+            // A file that is still being written fails to parse; the write that completes it
+            // triggers another event.
+            let Some(new_definitions) = current_definitions() else {
+                continue;
+            };
+
+            let change = diff_definitions(&definitions, &new_definitions);
+            definitions = new_definitions;
+            if change.is_empty() {
+                continue;
+            }
+
+            info!(
+                "Kubeconfig changed: {} added, {} removed, {} modified",
+                change.added.len(),
+                change.removed.len(),
+                change.modified.len()
+            );
+            for context in change.removed.iter().chain(&change.modified) {
+                invalidate_cached_clients(None, Some(context));
+            }
+
+            if let Err(err) = app_handle.emit("kubeconfig_changed", change) {
+                error!("Failed to emit kubeconfig change: {}", err);
+            }
+        }
+    }
+
+    /// Replaces the watcher with one on the files of the given source, emitting
+    /// `kubeconfig_changed` whenever the contexts they define change.
+    pub(crate) fn watch_kubeconfigs(app_handle: tauri::AppHandle, source: &KubeconfigSource) {
+        let (directories, files) = watch_targets(source);
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            match event {
+                Ok(event) if event.kind.is_access() => {}
+                Ok(event) => {
+                    let relevant = match &files {
+                        Some(files) => event
+                            .paths
+                            .iter()
+                            .flat_map(|path| event_paths(path))
+                            .any(|path| files.contains(&path)),
+                        None => true,
+                    };
+                    if relevant {
+                        let _ = tx.send(());
+                    }
+                }
+                Err(err) => warn!("Kubeconfig watcher error: {}", err),
+            }
+        });
+
+        let mut watcher = match watcher {
+            Ok(watcher) => watcher,
+            Err(err) => {
+                error!("Failed to create kubeconfig watcher: {}", err);
+                return;
+            }
+        };
+        for directory in &directories {
+            if let Err(err) = watcher.watch(directory, RecursiveMode::NonRecursive) {
+                warn!("Failed to watch {}: {}", directory.display(), err);
+            }
+        }
+
+        let definitions = current_definitions().unwrap_or_default();
+        let task = tauri::async_runtime::spawn(handle_changes(app_handle, rx, definitions));
+
+        let previous = KUBECONFIG_WATCHER.lock().unwrap().replace((watcher, task));
+        if let Some((_, task)) = previous {
+            task.abort();
+        }
+        debug!("Watching {} kubeconfig directories", directories.len());
+    }
+
+    #[tauri::command]
+    pub async fn watch_kubeconfig_changes(app_handle: tauri::AppHandle) {
+        watch_kubeconfigs(app_handle, &current_kubeconfig_source());
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn diff_context_definitions() {
+            let old = HashMap::from([
+                ("staging".to_string(), json!({ "cluster": { "server": "https://staging" } })),
+                ("production".to_string(), json!({ "cluster": { "server": "https://production" } })),
+                ("dev".to_string(), json!({ "cluster": { "server": "https://dev" } })),
+            ]);
+            let new = HashMap::from([
+                ("staging".to_string(), json!({ "cluster": { "server": "https://staging" } })),
+                ("production".to_string(), json!({ "cluster": { "server": "https://production-2" } })),
+                ("preview".to_string(), json!({ "cluster": { "server": "https://preview" } })),
+            ]);
+
+            let change = diff_definitions(&old, &new);
+            assert_eq!(change.added, vec!["preview"]);
+            assert_eq!(change.removed, vec!["dev"]);
+            assert_eq!(change.modified, vec!["production"]);
+            assert!(diff_definitions(&new, &new).is_empty());
+        }
+
+        fn kubeconfig(context: &str, token: &str) -> Kubeconfig {
+            Kubeconfig::from_yaml(&format!(
+                "apiVersion: v1
+kind: Config
+clusters:
+- name: {context}
+  cluster:
+    server: https://{context}.example.com
+contexts:
+- name: {context}
+  context:
+    cluster: {context}
+    user: admin
+users:
+- name: admin
+  user:
+    token: {token}
+"
+            ))
+            .unwrap()
+        }
+
+        fn definitions(staging_token: &str, production_token: &str) -> HashMap<String, Value> {
+            let configs = vec![
+                (PathBuf::from("staging"), kubeconfig("staging", staging_token)),
+                (PathBuf::from("production"), kubeconfig("production", production_token)),
+            ];
+            context_definitions(&client_kubeconfig(&configs).unwrap())
+        }
+
+        #[test]
+        fn report_only_edits_to_definitions_clients_use() {
+            let old = definitions("staging-token", "production-token");
+
+            // The user in the second file is shadowed by the one in the first
+            assert!(diff_definitions(&old, &definitions("staging-token", "rotated")).is_empty());
+
+            let change = diff_definitions(&old, &definitions("rotated", "production-token"));
+            assert_eq!(change.modified, vec!["production", "staging"]);
+        }
+
+        #[test]
+        fn resolve_relative_paths_of_missing_files() {
+            let current = fs::canonicalize(env::current_dir().unwrap()).unwrap();
+            assert_eq!(resolve_directory(Path::new("kubeconfig")), current.join("kubeconfig"));
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn expand_the_home_directory() {
+            let home = PathBuf::from(env::var_os("HOME").unwrap());
+            assert_eq!(expand_home(Path::new("~/.kube/config")), home.join(".kube/config"));
+            assert_eq!(expand_home(Path::new("/etc/kubeconfig")), PathBuf::from("/etc/kubeconfig"));
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn match_events_on_symlink_targets() {
+            let dir = fs::canonicalize(env::temp_dir())
+                .unwrap()
+                .join(format!("jet-pilot-watch-{}", uuid::Uuid::new_v4()));
+            fs::create_dir_all(dir.join("configs")).unwrap();
+            fs::write(dir.join("configs").join("cluster.yaml"), "").unwrap();
+            std::os::unix::fs::symlink(dir.join("configs").join("cluster.yaml"), dir.join("config")).unwrap();
+
+            let source = KubeconfigSource::Paths {
+                paths: vec![dir.join("config").to_string_lossy().into_owned()],
+            };
+            let (directories, files) = watch_targets(&source);
+            assert_eq!(directories, BTreeSet::from([dir.clone(), dir.join("configs")]));
+            assert_eq!(files.unwrap(), vec![dir.join("config"), dir.join("configs").join("cluster.yaml")]);
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+}
+
+pub mod watch {
+    use super::client::{client_with_context, ErrorKind, SerializableKubeError};
+    use futures::StreamExt;
+    use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+    use k8s_openapi::api::batch::v1::{CronJob, Job};
+    use k8s_openapi::api::core::v1::{
+        ConfigMap, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, Secret, Service,
+    };
+    use k8s_openapi::api::networking::v1::Ingress;
+    use kube::api::{Api, ApiResource, DynamicObject};
+    use kube::discovery::Scope;
+    use kube::runtime::{watcher, WatchStreamExt};
+    use kube::ResourceExt;
+    use serde::Serialize;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use tauri::async_runtime::JoinHandle;
+    use tauri::Emitter;
+    use tracing::{debug, info, warn};
+    use uuid::Uuid;
+
+    type ObjectStore = Arc<Mutex<HashMap<String, DynamicObject>>>;
+
+    struct ResourceWatch {
+        context: String,
+        namespace: String,
+        kind: String,
+        subscribers: u32,
+        objects: ObjectStore,
+        task: JoinHandle<()>,
+    }
+
+    static RESOURCE_WATCHES: Mutex<Option<HashMap<String, ResourceWatch>>> = Mutex::new(None);
+
+    #[derive(Clone, Serialize)]
+    #[serde(tag = "type")]
+    pub enum ResourceWatchEvent {
+        Added { object: DynamicObject },
+        Modified { object: DynamicObject },
+        Deleted { object: DynamicObject },
+        Synced { objects: Vec<DynamicObject> },
+        Error { message: String },
+    }
+
+    pub(crate) fn api_resource_for_kind(kind: &str) -> Option<(ApiResource, Scope)> {
+        let resource = match kind {
+            "pods" => (ApiResource::erase::<Pod>(&()), Scope::Namespaced),
+            "deployments" => (ApiResource::erase::<Deployment>(&()), Scope::Namespaced),
+            "statefulsets" => (ApiResource::erase::<StatefulSet>(&()), Scope::Namespaced),
+            "daemonsets" => (ApiResource::erase::<DaemonSet>(&()), Scope::Namespaced),
+            "replicasets" => (ApiResource::erase::<ReplicaSet>(&()), Scope::Namespaced),
+            "jobs" => (ApiResource::erase::<Job>(&()), Scope::Namespaced),
+            "cronjobs" => (ApiResource::erase::<CronJob>(&()), Scope::Namespaced),
+            "configmaps" => (ApiResource::erase::<ConfigMap>(&()), Scope::Namespaced),
+            "secrets" => (ApiResource::erase::<Secret>(&()), Scope::Namespaced),
+            "services" => (ApiResource::erase::<Service>(&()), Scope::Namespaced),
+            "ingresses" => (ApiResource::erase::<Ingress>(&()), Scope::Namespaced),
+            "persistentvolumeclaims" => {
+                (ApiResource::erase::<PersistentVolumeClaim>(&()), Scope::Namespaced)
+            }
+            "persistentvolumes" => (ApiResource::erase::<PersistentVolume>(&()), Scope::Cluster),
+            "namespaces" => (ApiResource::erase::<Namespace>(&()), Scope::Cluster),
+            "nodes" => (ApiResource::erase::<Node>(&()), Scope::Cluster),
+            _ => return None,
+        };
+
+        Some(resource)
+    }
+
+    // Adds a subscriber to the watch already running for the context, namespace and kind
+    fn reuse_watch(
+        watches: &mut HashMap<String, ResourceWatch>,
+        context: &str,
+        namespace: &str,
+        kind: &str,
+    ) -> Option<String> {
+        let (watch_id, watch) = watches
+            .iter_mut()
+            .find(|(_, w)| w.context == context && w.namespace == namespace && w.kind == kind)?;
+        watch.subscribers += 1;
+        debug!("Reusing resource watch {} ({} subscribers)", watch_id, watch.subscribers);
+        Some(watch_id.clone())
+    }
+
+    fn object_key(object: &DynamicObject) -> String {
+        object
+            .uid()
+            .unwrap_or_else(|| format!("{}/{}", object.namespace().unwrap_or_default(), object.name_any()))
+    }
+
+    fn emit_watch_event(app_handle: &tauri::AppHandle, watch_id: &str, event: ResourceWatchEvent) {
+        if let Err(err) = app_handle.emit(format!("resource_watch_{}", watch_id).as_ref(), event) {
+            warn!("Failed to emit event for resource watch {}: {}", watch_id, err);
+        }
+    }
+
+    async fn run_watch(
+        app_handle: tauri::AppHandle,
+        watch_id: String,
+        api: Api<DynamicObject>,
+        objects: ObjectStore,
+    ) {
+        // The watcher relists by itself whenever the server answers with 410 Gone, which
+        // surfaces here as a new Init..InitDone cycle that replaces the known objects.
+        let mut stream = watcher(api, watcher::Config::default()).default_backoff().boxed();
+        let mut buffer: HashMap<String, DynamicObject> = HashMap::new();
+
+        while let Some(event) = stream.next().await {
+            match event {
+                Ok(watcher::Event::Init) => {
+                    debug!("Resource watch {} is (re)listing objects", watch_id);
+                    buffer.clear();
+                }
+                Ok(watcher::Event::InitApply(object)) => {
+                    buffer.insert(object_key(&object), object);
+                }
+                Ok(watcher::Event::InitDone) => {
+                    let snapshot: Vec<DynamicObject> = buffer.values().cloned().collect();
+                    *objects.lock().unwrap() = std::mem::take(&mut buffer);
+                    debug!("Resource watch {} synced {} objects", watch_id, snapshot.len());
+                    emit_watch_event(&app_handle, &watch_id, ResourceWatchEvent::Synced { objects: snapshot });
+                }
+                Ok(watcher::Event::Apply(object)) => {
+                    let existed = objects
+                        .lock()
+                        .unwrap()
+                        .insert(object_key(&object), object.clone())
+                        .is_some();
+                    let event = if existed {
+                        ResourceWatchEvent::Modified { object }
+                    } else {
+                        ResourceWatchEvent::Added { object }
+                    };
+                    emit_watch_event(&app_handle, &watch_id, event);
+                }
+                Ok(watcher::Event::Delete(object)) => {
+                    objects.lock().unwrap().remove(&object_key(&object));
+                    emit_watch_event(&app_handle, &watch_id, ResourceWatchEvent::Deleted { object });
+                }
+                Err(err) => {
+                    warn!("Resource watch {} failed, retrying: {}", watch_id, err);
+                    emit_watch_event(
+                        &app_handle,
+                        &watch_id,
+                        ResourceWatchEvent::Error {
+                            message: err.to_string(),
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    #[tauri::command]
+    pub async fn subscribe_to_resource_watch(
+        app_handle: tauri::AppHandle,
+        context: &str,
+        namespace: &str,
+        kind: &str,
+    ) -> Result<String, SerializableKubeError> {
+        debug!("Subscribing to {} watch in namespace {} for context {}", kind, namespace, context);
+
+        {
+            let mut watches = RESOURCE_WATCHES.lock().unwrap();
+            let watches = watches.get_or_insert_with(HashMap::new);
+            if let Some(watch_id) = reuse_watch(watches, context, namespace, kind) {
+                return Ok(watch_id);
+            }
+        }
+
+        let (api_resource, scope) = api_resource_for_kind(kind).ok_or_else(|| SerializableKubeError::new(
+            ErrorKind::Unsupported,
+            "UnsupportedKind",
+            format!("Watching {} is not supported", kind),
+        ))?;
+
+        let client = client_with_context(context).await?;
+        let api: Api<DynamicObject> = match scope {
+            Scope::Namespaced if !namespace.is_empty() => {
+                Api::namespaced_with(client, namespace, &api_resource)
+            }
+            _ => Api::all_with(client, &api_resource),
+        };
+
+        // A concurrent subscribe may have started the same watch while the client was created
+        let mut watches = RESOURCE_WATCHES.lock().unwrap();
+        let watches = watches.get_or_insert_with(HashMap::new);
+        if let Some(watch_id) = reuse_watch(watches, context, namespace, kind) {
+            return Ok(watch_id);
+        }
+
+        let watch_id = Uuid::new_v4().to_string();
+        let objects: ObjectStore = Arc::new(Mutex::new(HashMap::new()));
+        let task = tauri::async_runtime::spawn(run_watch(
+            app_handle,
+            watch_id.clone(),
+            api,
+            objects.clone(),
+        ));
+
+        watches.insert(
+            watch_id.clone(),
+            ResourceWatch {
+                context: context.to_string(),
+                namespace: namespace.to_string(),
+                kind: kind.to_string(),
+                subscribers: 1,
+                objects,
+                task,
+            },
+        );
+
+        info!("Started {} watch {} in namespace {} for context {}", kind, watch_id, namespace, context);
+        Ok(watch_id)
+    }
+
+    #[tauri::command]
+    pub async fn get_resource_watch_snapshot(watch_id: &str) -> Vec<DynamicObject> {
+        debug!("Retrieving snapshot for resource watch {}", watch_id);
+        RESOURCE_WATCHES
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|watches| watches.get(watch_id))
+            .map(|watch| watch.objects.lock().unwrap().values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    #[tauri::command]
+    pub async fn unsubscribe_from_resource_watch(watch_id: &str) {
+        debug!("Unsubscribing from resource watch {}", watch_id);
+        let mut watches = RESOURCE_WATCHES.lock().unwrap();
+        let Some(watches) = watches.as_mut() else {
+            return;
+        };
+
+        if let Some(watch) = watches.get_mut(watch_id) {
+            watch.subscribers = watch.subscribers.saturating_sub(1);
+            if watch.subscribers == 0 {
+                watch.task.abort();
+                watches.remove(watch_id);
+                info!("Stopped resource watch {}", watch_id);
+            }
+        }
+    }
+}
+
+pub mod dynamic {
+    use super::client::{
+        apply_params, list_objects, prepare_for_apply, DeletionResult, ErrorKind, ListResult,
+        PageOptions, RequestOptions, RequestScope, SerializableKubeError,
+    };
+    use super::rollout::track_rollout_if_requested;
+    use either::Either;
+    use kube::api::{
+        Api, ApiResource, DeleteParams, DynamicObject, ListParams, Patch, PatchParams, PostParams,
+    };
+    use kube::core::GroupVersionKind;
+    use kube::discovery::{self, Scope};
+    use kube::Client;
+    use serde::Deserialize;
+    use tracing::{debug, error, info};
+
+    /// Identifies any kind the cluster serves, as reported by `get_api_groups` and
+    /// `get_api_group_resources`. The plural and scope are looked up through discovery
+    /// when the caller does not provide them.
+    #[derive(Clone, Debug, Deserialize)]
+    pub struct ResourceType {
+        pub group: String,
+        pub version: String,
+        pub kind: String,
+        pub plural: Option<String>,
+        pub namespaced: Option<bool>,
+    }
+
+    impl ResourceType {
+        fn gvk(&self) -> GroupVersionKind {
+            GroupVersionKind::gvk(&self.group, &self.version, &self.kind)
+        }
+
+        /// The kind used to opt into rollout tracking; only `apps` kinds can be tracked.
+        pub(crate) fn rollout_kind(&self) -> String {
+            if self.group == "apps" {
+                self.kind.clone()
+            } else {
+                format!("{}.{}", self.kind, self.group)
+            }
+        }
+    }
+
+    pub(crate) async fn resolve_api_resource(
+        client: &Client,
+        resource_type: &ResourceType,
+    ) -> Result<(ApiResource, Scope), SerializableKubeError> {
+        let gvk = resource_type.gvk();
+
+        if let (Some(plural), Some(namespaced)) = (&resource_type.plural, resource_type.namespaced) {
+            let scope = if namespaced { Scope::Namespaced } else { Scope::Cluster };
+            return Ok((ApiResource::from_gvk_with_plural(&gvk, plural), scope));
+        }
+
+        debug!("Discovering API resource for {}/{} {}", gvk.group, gvk.version, gvk.kind);
+        let (api_resource, capabilities) = discovery::pinned_kind(client, &gvk).await.map_err(|err| {
+            error!("Failed to discover {}/{} {}: {}", gvk.group, gvk.version, gvk.kind, err);
+            SerializableKubeError::from(err)
+        })?;
+
+        Ok((api_resource, capabilities.scope))
+    }
+
+    /// Builds the API for `resource_type`, with client creation and discovery bound to
+    /// the deadline and cancellation of `scope`.
+    pub(crate) async fn dynamic_api(
+        context: &str,
+        resource_type: &ResourceType,
+        namespace: &str,
+        scope: &RequestScope,
+    ) -> Result<Api<DynamicObject>, SerializableKubeError> {
+        let client = scope.client(context).await?;
+        let (api_resource, resource_scope) =
+            scope.read(|| resolve_api_resource(&client, resource_type)).await?;
+
+        Ok(match resource_scope {
+            Scope::Namespaced if !namespace.is_empty() => {
+                Api::namespaced_with(client, namespace, &api_resource)
+            }
+            _ => Api::all_with(client, &api_resource),
+        })
+    }
+
+    #[tauri::command]
+    pub async fn list_resources(
+        context: &str,
+        resource_type: ResourceType,
+        namespace: &str,
+        label_selector: &str,
+        field_selector: &str,
+        page: Option<PageOptions>,
+        options: Option<RequestOptions>,
+    ) -> Result<ListResult<DynamicObject>, SerializableKubeError> {
+        debug!("Listing {} in namespace {} for context {}", resource_type.kind, namespace, context);
+        let scope = RequestScope::new(options.as_ref());
+        let api = dynamic_api(context, &resource_type, namespace, &scope).await?;
+
+        let params = ListParams::default()
+            .labels(label_selector)
+            .fields(field_selector);
+        let objects = list_objects(&api, params, page, &scope)
+            .await
+            .inspect_err(|err| {
+                error!("Failed to list {} in namespace {}: {}", resource_type.kind, namespace, err.message);
+            })?;
+
+        info!("Found {} {} in namespace {}", objects.len(), resource_type.kind, namespace);
+        Ok(objects)
+    }
+
+    #[tauri::command]
+    pub async fn get_resource(
+        context: &str,
+        resource_type: ResourceType,
+        namespace: &str,
+        name: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<DynamicObject, SerializableKubeError> {
+        debug!("Getting {} {}/{}", resource_type.kind, namespace, name);
+        let scope = RequestScope::new(options.as_ref());
+        let api = dynamic_api(context, &resource_type, namespace, &scope).await?;
+
+        scope.read(|| api.get(name)).await.inspect_err(|err| {
+            error!("Failed to get {} {}/{}: {}", resource_type.kind, namespace, name, err.message);
+        })
+    }
+
+    #[tauri::command]
+    pub async fn replace_resource(
+        context: &str,
+        resource_type: ResourceType,
+        namespace: &str,
+        name: &str,
+        object: DynamicObject,
+        options: Option<RequestOptions>,
+    ) -> Result<DynamicObject, SerializableKubeError> {
+        debug!("Replacing {} {}/{}", resource_type.kind, namespace, name);
+        let scope = RequestScope::new(options.as_ref());
+        let api = dynamic_api(context, &resource_type, namespace, &scope).await?;
+
+        let params = PostParams::default();
+        let object = scope.write(|| api.replace(name, &params, &object)).await.inspect_err(|err| {
+            error!("Failed to replace {} {}/{}: {}", resource_type.kind, namespace, name, err.message);
+        })?;
+
+        info!("Successfully replaced {} {}/{}", resource_type.kind, namespace, name);
+        Ok(object)
+    }
+
+    #[tauri::command]
+    pub async fn patch_resource(
+        context: &str,
+        resource_type: ResourceType,
+        namespace: &str,
+        name: &str,
+        patch: serde_json::Value,
+        patch_type: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<DynamicObject, SerializableKubeError> {
+        debug!("Patching {} {}/{} using {} patch", resource_type.kind, namespace, name, patch_type);
+        let patch = match patch_type {
+            "merge" => Patch::Merge(patch),
+            "strategic" => Patch::Strategic(patch),
+            "json" => {
+                let operations: json_patch::Patch = serde_json::from_value(patch).map_err(|err| {
+                    SerializableKubeError::new(ErrorKind::Invalid, "InvalidJsonPatch", err.to_string())
+                })?;
+                Patch::Json(operations)
+            }
+            _ => {
+                return Err(SerializableKubeError::new(
+                    ErrorKind::Unsupported,
+                    "UnsupportedPatchType",
+                    format!("Unsupported patch type: {}", patch_type),
+                ))
+            }
+        };
+
+        let scope = RequestScope::new(options.as_ref());
+        let api = dynamic_api(context, &resource_type, namespace, &scope).await?;
+
+        let params = PatchParams::default();
+        let object = scope.write(|| api.patch(name, &params, &patch)).await.inspect_err(|err| {
+            error!("Failed to patch {} {}/{}: {}", resource_type.kind, namespace, name, err.message);
+        })?;
+
+        info!("Successfully patched {} {}/{}", resource_type.kind, namespace, name);
+        Ok(object)
+    }
+
+    #[tauri::command]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn apply_resource(
+        app_handle: tauri::AppHandle,
+        context: &str,
+        resource_type: ResourceType,
+        namespace: &str,
+        name: &str,
+        mut object: DynamicObject,
+        force: bool,
+        rollout_tracking_id: Option<String>,
+        options: Option<RequestOptions>,
+    ) -> Result<DynamicObject, SerializableKubeError> {
+        debug!("Applying {} {}/{} (force: {})", resource_type.kind, namespace, name, force);
+        let scope = RequestScope::new(options.as_ref());
+        let api = dynamic_api(context, &resource_type, namespace, &scope).await?;
+
+        prepare_for_apply(&mut object.metadata);
+        let params = apply_params(force);
+        let patch = Patch::Apply(&object);
+        let object = scope.write(|| api.patch(name, &params, &patch)).await.inspect_err(|err| {
+            error!("Failed to apply {} {}/{}: {}", resource_type.kind, namespace, name, err.message);
+        })?;
+
+        info!("Successfully applied {} {}/{}", resource_type.kind, namespace, name);
+        if rollout_tracking_id.is_some() {
+            let client = scope.client(context).await?;
+            track_rollout_if_requested(&app_handle, client, namespace, &resource_type.rollout_kind(), name, rollout_tracking_id);
+        }
+        Ok(object)
+    }
+
+    #[tauri::command]
+    pub async fn delete_resource(
+        context: &str,
+        resource_type: ResourceType,
+        namespace: &str,
+        name: &str,
+        grace_period_seconds: Option<u32>,
+        options: Option<RequestOptions>,
+    ) -> Result<DeletionResult, SerializableKubeError> {
+        debug!("Deleting {} {}/{}", resource_type.kind, namespace, name);
+        let scope = RequestScope::new(options.as_ref());
+        let api = dynamic_api(context, &resource_type, namespace, &scope).await?;
+
+        let params = match grace_period_seconds {
+            Some(seconds) => DeleteParams::default().grace_period(seconds),
+            None => DeleteParams::default(),
+        };
+
+        match scope.write(|| api.delete(name, &params)).await {
+            Ok(Either::Left(_object)) => {
+                info!("{} {}/{} deleted successfully", resource_type.kind, namespace, name);
+                Ok(DeletionResult::Deleted(name.to_string()))
+            }
+            Ok(Either::Right(_status)) => {
+                debug!("{} {}/{} deletion in progress", resource_type.kind, namespace, name);
+                Ok(DeletionResult::Pending("Deletion in progress".to_string()))
+            }
+            Err(err) => {
+                error!("Failed to delete {} {}/{}: {}", resource_type.kind, namespace, name, err.message);
+                Err(err)
+            }
+        }
+    }
+}
+
+pub mod port_forward {
+    use super::client::{ErrorKind, RequestOptions, RequestScope, SerializableKubeError};
+    use k8s_openapi::api::apps::v1::Deployment;
+    use k8s_openapi::api::core::v1::{Pod, Service};
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+    use kube::api::{Api, ListParams, Portforwarder};
+    use kube::ResourceExt;
+    use serde::{Deserialize, Serialize};
+    use std::collections::{BTreeMap, HashMap};
+    use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tauri::async_runtime::JoinHandle;
+    use tauri::Emitter;
+    use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio::time::sleep;
+    use tracing::{debug, error, info, warn};
+    use uuid::Uuid;
+
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub enum PortForwardTargetKind {
+        Pod,
+        Service,
+        Deployment,
+    }
+
+    #[derive(Clone, Debug, Serialize)]
+    #[serde(tag = "state")]
+    pub enum PortForwardStatus {
+        Listening,
+        Forwarding { pod: String },
+        Error { message: String },
+        Stopped,
+    }
+
+    #[derive(Clone, Debug, Serialize)]
+    pub struct PortForwardInfo {
+        id: String,
+        context: String,
+        namespace: String,
+        target_kind: PortForwardTargetKind,
+        target_name: String,
+        address: String,
+        local_port: u16,
+        remote_port: u16,
+        status: PortForwardStatus,
+        active_connections: u32,
+        bytes_sent: u64,
+        bytes_received: u64,
+    }
+
+    struct PortForwardState {
+        info: Mutex<PortForwardInfo>,
+        current_pod: Mutex<Option<(String, u16)>>,
+        active_connections: AtomicU32,
+        bytes_sent: AtomicU64,
+        bytes_received: AtomicU64,
+    }
+
+    impl PortForwardState {
+        fn snapshot(&self) -> PortForwardInfo {
+            let mut info = self.info.lock().unwrap().clone();
+            info.active_connections = self.active_connections.load(Ordering::Relaxed);
+            info.bytes_sent = self.bytes_sent.load(Ordering::Relaxed);
+            info.bytes_received = self.bytes_received.load(Ordering::Relaxed);
+            info
+        }
+
+        fn set_status(&self, app_handle: &tauri::AppHandle, status: PortForwardStatus) {
+            self.info.lock().unwrap().status = status;
+            let info = self.snapshot();
+            if let Err(err) = app_handle.emit(format!("port_forward_{}", info.id).as_ref(), info) {
+                warn!("Failed to emit port forward status: {}", err);
+            }
+        }
+    }
+
+    struct PortForward {
+        state: Arc<PortForwardState>,
+        task: JoinHandle<()>,
+    }
+
+    static PORT_FORWARDS: Mutex<Option<HashMap<String, PortForward>>> = Mutex::new(None);
+
+    const ACCEPT_RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+    const ACCEPT_RETRY_MAX_DELAY: Duration = Duration::from_secs(5);
+
+    fn to_label_selector(labels: &BTreeMap<String, String>) -> String {
+        labels
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    fn is_ready(pod: &Pod) -> bool {
+        pod.metadata.deletion_timestamp.is_none()
+            && pod
+                .status
+                .as_ref()
+                .and_then(|status| status.conditions.as_ref())
+                .is_some_and(|conditions| {
+                    conditions
+                        .iter()
+                        .any(|c| c.type_ == "Ready" && c.status == "True")
+                })
+    }
+
+    fn container_port_by_name(pod: &Pod, port_name: &str) -> Option<u16> {
+        pod.spec
+            .as_ref()?
+            .containers
+            .iter()
+            .flat_map(|container| container.ports.iter().flatten())
+            .find(|port| port.name.as_deref() == Some(port_name))
+            .map(|port| port.container_port as u16)
+    }
+
+    async fn ready_pod_for_selector(
+        pods: &Api<Pod>,
+        labels: &BTreeMap<String, String>,
+        scope: &RequestScope,
+    ) -> Result<Pod, String> {
+        let selector = to_label_selector(labels);
+        let params = ListParams::default().labels(&selector);
+        let candidates = scope.read(|| pods.list(&params)).await.map_err(|err| err.message)?;
+
+        candidates
+            .items
+            .into_iter()
+            .find(is_ready)
+            .ok_or_else(|| format!("No ready pod matches selector {}", selector))
+    }
+
+    /// Resolves the pod and container port that connections should currently be forwarded to.
+    async fn resolve_target(
+        pods: &Api<Pod>,
+        namespace: &str,
+        target_kind: &PortForwardTargetKind,
+        target_name: &str,
+        remote_port: u16,
+        scope: &RequestScope,
+    ) -> Result<(String, u16), String> {
+        match target_kind {
+            PortForwardTargetKind::Pod => Ok((target_name.to_string(), remote_port)),
+            PortForwardTargetKind::Service => {
+                let services: Api<Service> = Api::namespaced(pods.clone().into_client(), namespace);
+                let service = scope.read(|| services.get(target_name)).await.map_err(|err| err.message)?;
+                let spec = service.spec.unwrap_or_default();
+                let selector = spec
+                    .selector
+                    .ok_or_else(|| format!("Service {} has no selector", target_name))?;
+
+                let pod = ready_pod_for_selector(pods, &selector, scope).await?;
+                let target_port = spec
+                    .ports
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|port| port.port as u16 == remote_port)
+                    .and_then(|port| port.target_port);
+
+                let port = match target_port {
+                    Some(IntOrString::Int(port)) => port as u16,
+                    Some(IntOrString::String(name)) => container_port_by_name(&pod, &name)
+                        .ok_or_else(|| format!("Pod {} has no port named {}", pod.name_any(), name))?,
+                    None => remote_port,
+                };
+
+                Ok((pod.name_any(), port))
+            }
+            PortForwardTargetKind::Deployment => {
+                let deployments: Api<Deployment> =
+                    Api::namespaced(pods.clone().into_client(), namespace);
+                let deployment = scope.read(|| deployments.get(target_name)).await.map_err(|err| err.message)?;
+                let labels = deployment
+                    .spec
+                    .and_then(|spec| spec.selector.match_labels)
+                    .ok_or_else(|| format!("Deployment {} has no label selector", target_name))?;
+
+                let pod = ready_pod_for_selector(pods, &labels, scope).await?;
+                Ok((pod.name_any(), remote_port))
+            }
+        }
+    }
+
+    async fn pump<R, W>(mut reader: R, mut writer: W, counter: &AtomicU64) -> std::io::Result<()>
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let mut buffer = vec![0u8; 16 * 1024];
+        loop {
+            let read = reader.read(&mut buffer).await?;
+            if read == 0 {
+                return writer.shutdown().await;
+            }
+            writer.write_all(&buffer[..read]).await?;
+            counter.fetch_add(read as u64, Ordering::Relaxed);
+        }
+    }
+
+    async fn open_forwarder(
+        pods: &Api<Pod>,
+        pod: &str,
+        port: u16,
+        scope: &RequestScope,
+    ) -> Result<Portforwarder, SerializableKubeError> {
+        let ports = [port];
+        scope.read(|| pods.portforward(pod, &ports)).await
+    }
+
+    async fn forward_connection(
+        app_handle: &tauri::AppHandle,
+        pods: &Api<Pod>,
+        state: &PortForwardState,
+        options: &RequestOptions,
+        connection: TcpStream,
+    ) -> Result<(), String> {
+        let scope = RequestScope::new(Some(options));
+        let (namespace, target_kind, target_name, remote_port) = {
+            let info = state.info.lock().unwrap();
+            (info.namespace.clone(), info.target_kind.clone(), info.target_name.clone(), info.remote_port)
+        };
+
+        // Reuse the pod we forwarded to before, and only resolve a new one when it is gone
+        let cached = state.current_pod.lock().unwrap().clone();
+        let mut forwarder = match cached {
+            Some((pod, port)) => match open_forwarder(pods, &pod, port, &scope).await {
+                Ok(forwarder) => Some((forwarder, pod, port)),
+                Err(err) => {
+                    debug!("Port forward to pod {} failed, resolving a new pod: {}", pod, err.message);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        if forwarder.is_none() {
+            let (pod, port) =
+                resolve_target(pods, &namespace, &target_kind, &target_name, remote_port, &scope).await?;
+            let portforwarder = open_forwarder(pods, &pod, port, &scope).await.map_err(|err| err.message)?;
+            state.current_pod.lock().unwrap().replace((pod.clone(), port));
+            forwarder = Some((portforwarder, pod, port));
+        }
+
+        let (mut forwarder, pod, port) = forwarder.unwrap();
+        let upstream = forwarder
+            .take_stream(port)
+            .ok_or_else(|| format!("Port {} is not available on pod {}", port, pod))?;
+
+        state.active_connections.fetch_add(1, Ordering::Relaxed);
+        state.set_status(app_handle, PortForwardStatus::Forwarding { pod: pod.clone() });
+
+        let (client_reader, client_writer) = tokio::io::split(connection);
+        let (upstream_reader, upstream_writer) = tokio::io::split(upstream);
+        let result = tokio::try_join!(
+            pump(client_reader, upstream_writer, &state.bytes_sent),
+            pump(upstream_reader, client_writer, &state.bytes_received),
+        );
+
+        forwarder.abort();
+        let remaining = state.active_connections.fetch_sub(1, Ordering::Relaxed) - 1;
+        if remaining == 0 {
+            state.set_status(app_handle, PortForwardStatus::Listening);
+        }
+
+        result.map(|_| ()).map_err(|err| err.to_string())
+    }
+
+    async fn accept_connections(
+        app_handle: tauri::AppHandle,
+        pods: Api<Pod>,
+        listener: TcpListener,
+        state: Arc<PortForwardState>,
+        options: RequestOptions,
+    ) {
+        // Connections live in the set so they are torn down together with the listener
+        let mut connections = tokio::task::JoinSet::new();
+        let mut retry_delay = ACCEPT_RETRY_BASE_DELAY;
+        let mut accept_error: Option<String> = None;
+        loop {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                Some(_) = connections.join_next(), if !connections.is_empty() => continue,
+            };
+
+            let connection = match accepted {
+                Ok((connection, peer)) => {
+                    debug!("Accepted port forward connection from {}", peer);
+                    retry_delay = ACCEPT_RETRY_BASE_DELAY;
+                    accept_error = None;
+                    connection
+                }
+                Err(err) => {
+                    // Failures such as running out of file descriptors last a while, so back
+                    // off and only report an error once until it changes
+                    let message = err.to_string();
+                    if accept_error.as_ref() != Some(&message) {
+                        error!("Failed to accept port forward connection: {}", message);
+                        state.set_status(&app_handle, PortForwardStatus::Error { message: message.clone() });
+                        accept_error = Some(message);
+                    }
+                    sleep(retry_delay).await;
+                    retry_delay = (retry_delay * 2).min(ACCEPT_RETRY_MAX_DELAY);
+                    continue;
+                }
+            };
+
+            let app_handle = app_handle.clone();
+            let pods = pods.clone();
+            let state = state.clone();
+            let options = options.clone();
+            connections.spawn(async move {
+                if let Err(message) = forward_connection(&app_handle, &pods, &state, &options, connection).await {
+                    warn!("Port forward connection failed: {}", message);
+                    state.set_status(&app_handle, PortForwardStatus::Error { message });
+                }
+            });
+        }
+    }
+
+    #[tauri::command]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn start_port_forward(
+        app_handle: tauri::AppHandle,
+        context: &str,
+        namespace: &str,
+        target_kind: PortForwardTargetKind,
+        target_name: &str,
+        remote_port: u16,
+        local_port: Option<u16>,
+        address: Option<String>,
+        options: Option<RequestOptions>,
+    ) -> Result<PortForwardInfo, SerializableKubeError> {
+        debug!(
+            "Starting port forward to {:?} {}/{}:{} in context {}",
+            target_kind, namespace, target_name, remote_port, context
+        );
+        let client = RequestScope::new(options.as_ref()).client(context).await?;
+        let pods: Api<Pod> = Api::namespaced(client, namespace);
+
+        let address = address.unwrap_or_else(|| "127.0.0.1".to_string());
+        let listener = TcpListener::bind((address.as_str(), local_port.unwrap_or(0)))
+            .await
+            .map_err(|err| {
+                error!("Failed to bind local port for port forward: {}", err);
+                SerializableKubeError::new(
+                    ErrorKind::Network,
+                    "PortForwardBindFailed",
+                    err.to_string(),
+                )
+            })?;
+        let local_port = listener.local_addr().map(|addr| addr.port()).unwrap_or_default();
+
+        let id = Uuid::new_v4().to_string();
+        let state = Arc::new(PortForwardState {
+            info: Mutex::new(PortForwardInfo {
+                id: id.clone(),
+                context: context.to_string(),
+                namespace: namespace.to_string(),
+                target_kind,
+                target_name: target_name.to_string(),
+                address,
+                local_port,
+                remote_port,
+                status: PortForwardStatus::Listening,
+                active_connections: 0,
+                bytes_sent: 0,
+                bytes_received: 0,
+            }),
+            current_pod: Mutex::new(None),
+            active_connections: AtomicU32::new(0),
+            bytes_sent: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
+        });
+
+        // Connections outlive this command, so they keep its deadline and retries but cannot
+        // be cancelled through its request id
+        let connection_options = RequestOptions {
+            request_id: None,
+            ..options.unwrap_or_default()
+        };
+        let task = tauri::async_runtime::spawn(accept_connections(
+            app_handle,
+            pods,
+            listener,
+            state.clone(),
+            connection_options,
+        ));
+        let info = state.snapshot();
+
+        PORT_FORWARDS
+            .lock()
+            .unwrap()
+            .get_or_insert_with(HashMap::new)
+            .insert(id.clone(), PortForward { state, task });
+
+        info!("Forwarding local port {} to {}/{}:{}", local_port, namespace, target_name, remote_port);
+        Ok(info)
+    }
+
+    #[tauri::command]
+    pub async fn list_port_forwards() -> Vec<PortForwardInfo> {
+        PORT_FORWARDS
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|forwards| forwards.values().map(|forward| forward.state.snapshot()).collect())
+            .unwrap_or_default()
+    }
+
+    #[tauri::command]
+    pub async fn stop_port_forward(app_handle: tauri::AppHandle, id: &str) {
+        debug!("Stopping port forward {}", id);
+        let forward = PORT_FORWARDS
+            .lock()
+            .unwrap()
+            .as_mut()
+            .and_then(|forwards| forwards.remove(id));
+
+        if let Some(forward) = forward {
+            forward.task.abort();
+            forward.state.set_status(&app_handle, PortForwardStatus::Stopped);
+            info!("Stopped port forward {}", id);
+        }
+    }
+}
+
+pub mod diff {
+    use super::client::{
+        apply_params, prepare_for_apply, RequestOptions, RequestScope, SerializableKubeError,
+    };
+    use super::dynamic::{dynamic_api, ResourceType};
+    use kube::api::{DynamicObject, Patch, PostParams};
+    use serde::{Deserialize, Serialize};
+    use serde_json::Value;
+    use tracing::{debug, error, info};
+
+    #[derive(Clone, Debug, Deserialize)]
+    pub enum DiffStrategy {
+        Replace,
+        Apply,
+    }
+
+    #[derive(Clone, Debug, Serialize)]
+    pub enum ChangeType {
+        Added,
+        Removed,
+        Modified,
+    }
+
+    #[derive(Clone, Debug, Serialize)]
+    pub struct FieldChange {
+        path: String,
+        change: ChangeType,
+        old: Option<Value>,
+        new: Option<Value>,
+    }
+
+    #[derive(Clone, Debug, Serialize)]
+    pub struct ResourceDiff {
+        live: Value,
+        dry_run: Value,
+        changes: Vec<FieldChange>,
+    }
+
+    fn child_path(path: &str, key: &str) -> String {
+        let plain = !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        match (path.is_empty(), plain) {
+            (true, true) => key.to_string(),
+            (false, true) => format!("{}.{}", path, key),
+            (_, false) => format!("{}[{:?}]", path, key),
+        }
+    }
+
+    fn collect_changes(path: &str, old: &Value, new: &Value, changes: &mut Vec<FieldChange>) {
+        match (old, new) {
+            (Value::Object(old_map), Value::Object(new_map)) => {
+                for (key, old_value) in old_map {
+                    match new_map.get(key) {
+                        Some(new_value) => collect_changes(&child_path(path, key), old_value, new_value, changes),
+                        None => changes.push(FieldChange {
+                            path: child_path(path, key),
+                            change: ChangeType::Removed,
+                            old: Some(old_value.clone()),
+                            new: None,
+                        }),
+                    }
+                }
+                for (key, new_value) in new_map {
+                    if !old_map.contains_key(key) {
+                        changes.push(FieldChange {
+                            path: child_path(path, key),
+                            change: ChangeType::Added,
+                            old: None,
+                            new: Some(new_value.clone()),
+                        });
+                    }
+                }
+            }
+            (Value::Array(old_items), Value::Array(new_items)) => {
+                for index in 0..old_items.len().max(new_items.len()) {
+                    let item_path = format!("{}[{}]", path, index);
+                    match (old_items.get(index), new_items.get(index)) {
+                        (Some(old_item), Some(new_item)) => {
+                            collect_changes(&item_path, old_item, new_item, changes)
+                        }
+                        (Some(old_item), None) => changes.push(FieldChange {
+                            path: item_path,
+                            change: ChangeType::Removed,
+                            old: Some(old_item.clone()),
+                            new: None,
+                        }),
+                        (None, Some(new_item)) => changes.push(FieldChange {
+                            path: item_path,
+                            change: ChangeType::Added,
+                            old: None,
+                            new: Some(new_item.clone()),
+                        }),
+                        (None, None) => {}
+                    }
+                }
+            }
+            _ if old != new => changes.push(FieldChange {
+                path: path.to_string(),
+                change: ChangeType::Modified,
+                old: Some(old.clone()),
+                new: Some(new.clone()),
+            }),
+            _ => {}
+        }
+    }
+
+    /// Field-level differences between two JSON documents, with paths like
+    /// `spec.template.spec.containers[0].image`.
+    pub(crate) fn diff_values(old: &Value, new: &Value) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        collect_changes("", old, new, &mut changes);
+        changes
+    }
+
+    /// Strips the fields that are maintained by the API server and only add noise to a diff.
+    fn normalize(object: &DynamicObject) -> Value {
+        let mut value = serde_json::to_value(object).unwrap_or(Value::Null);
+        if let Some(obj) = value.as_object_mut() {
+            obj.remove("status");
+            if let Some(metadata) = obj.get_mut("metadata").and_then(Value::as_object_mut) {
+                metadata.remove("managedFields");
+                metadata.remove("resourceVersion");
+                metadata.remove("generation");
+            }
+        }
+        value
+    }
+
+    #[tauri::command]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn diff_resource(
+        context: &str,
+        resource_type: ResourceType,
+        namespace: &str,
+        name: &str,
+        mut object: DynamicObject,
+        strategy: DiffStrategy,
+        force: Option<bool>,
+        options: Option<RequestOptions>,
+    ) -> Result<ResourceDiff, SerializableKubeError> {
+        debug!("Diffing {:?} of {} {}/{}", strategy, resource_type.kind, namespace, name);
+        let scope = RequestScope::new(options.as_ref());
+        let api = dynamic_api(context, &resource_type, namespace, &scope).await?;
+
+        let live = scope.read(|| api.get(name)).await.inspect_err(|err| {
+            error!("Failed to get {} {}/{}: {}", resource_type.kind, namespace, name, err.message);
+        })?;
+
+        let dry_run = match strategy {
+            DiffStrategy::Replace => {
+                let params = PostParams {
+                    dry_run: true,
+                    ..Default::default()
+                };
+                scope.write(|| api.replace(name, &params, &object)).await
+            }
+            DiffStrategy::Apply => {
+                prepare_for_apply(&mut object.metadata);
+                let params = apply_params(force.unwrap_or(false)).dry_run();
+                let patch = Patch::Apply(&object);
+                scope.write(|| api.patch(name, &params, &patch)).await
+            }
+        }
+        .inspect_err(|err| {
+            error!("Dry-run of {} {}/{} failed: {}", resource_type.kind, namespace, name, err.message);
+        })?;
+
+        let live = normalize(&live);
+        let dry_run = normalize(&dry_run);
+        let changes = diff_values(&live, &dry_run);
+
+        info!("Dry-run of {} {}/{} changes {} fields", resource_type.kind, namespace, name, changes.len());
+        Ok(ResourceDiff {
+            live,
+            dry_run,
+            changes,
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use serde_json::json;
+
+        fn summarize(changes: &[FieldChange]) -> Vec<(String, &'static str)> {
+            changes
+                .iter()
+                .map(|change| {
+                    let kind = match change.change {
+                        ChangeType::Added => "added",
+                        ChangeType::Removed => "removed",
+                        ChangeType::Modified => "modified",
+                    };
+                    (change.path.clone(), kind)
+                })
+                .collect()
+        }
+
+        #[test]
+        fn equal_documents_have_no_changes() {
+            let value = json!({"spec": {"replicas": 2, "ports": [80, 443]}});
+            assert!(diff_values(&value, &value).is_empty());
+        }
+
+        #[test]
+        fn diff_nested_fields() {
+            let old = json!({
+                "metadata": {"labels": {"app": "web", "tier": "frontend"}},
+                "spec": {"replicas": 2}
+            });
+            let new = json!({
+                "metadata": {"labels": {"app": "web", "track": "canary"}},
+                "spec": {"replicas": 3}
+            });
+
+            assert_eq!(
+                summarize(&diff_values(&old, &new)),
+                vec![
+                    ("metadata.labels.tier".to_string(), "removed"),
+                    ("metadata.labels.track".to_string(), "added"),
+                    ("spec.replicas".to_string(), "modified"),
+                ]
+            );
+        }
+
+        #[test]
+        fn diff_array_items_by_index() {
+            let old = json!({"containers": [{"image": "nginx:1.25"}, {"image": "envoy"}]});
+            let new = json!({"containers": [{"image": "nginx:1.27"}]});
+
+            let changes = diff_values(&old, &new);
+            assert_eq!(
+                summarize(&changes),
+                vec![
+                    ("containers[0].image".to_string(), "modified"),
+                    ("containers[1]".to_string(), "removed"),
+                ]
+            );
+            assert_eq!(changes[0].old, Some(json!("nginx:1.25")));
+            assert_eq!(changes[0].new, Some(json!("nginx:1.27")));
+        }
+
+        #[test]
+        fn quote_keys_that_are_not_plain_identifiers() {
+            let old = json!({"metadata": {"annotations": {}}});
+            let new = json!({"metadata": {"annotations": {"app.kubernetes.io/name": "web"}}});
+
+            assert_eq!(
+                summarize(&diff_values(&old, &new)),
+                vec![("metadata.annotations[\"app.kubernetes.io/name\"]".to_string(), "added")]
+            );
+        }
+
+        #[test]
+        fn type_changes_are_modifications() {
+            let changes = diff_values(&json!({"port": "80"}), &json!({"port": 80}));
+            assert_eq!(summarize(&changes), vec![("port".to_string(), "modified")]);
+        }
+    }
+}
+
+pub mod events {
+    use super::client::{RequestOptions, RequestScope, SerializableKubeError};
+    use futures::StreamExt;
+    use k8s_openapi::api::core::v1::Event;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+    use chrono::{DateTime, Utc};
+    use kube::api::{Api, ListParams};
+    use kube::runtime::{watcher, WatchStreamExt};
+    use kube::{Client, ResourceExt};
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tauri::async_runtime::JoinHandle;
+    use tauri::Emitter;
+    use tracing::{debug, error, info, warn};
+    use uuid::Uuid;
+
+    static EVENT_WATCHES: Mutex<Option<HashMap<String, JoinHandle<()>>>> = Mutex::new(None);
+
+    #[derive(Clone, Debug, Default, Deserialize)]
+    pub struct EventFilter {
+        pub involved_object_kind: Option<String>,
+        pub involved_object_name: Option<String>,
+        pub involved_object_uid: Option<String>,
+        pub event_type: Option<String>,
+    }
+
+    impl EventFilter {
+        fn field_selector(&self) -> String {
+            [
+                ("involvedObject.kind", &self.involved_object_kind),
+                ("involvedObject.name", &self.involved_object_name),
+                ("involvedObject.uid", &self.involved_object_uid),
+                ("type", &self.event_type),
+            ]
+            .into_iter()
+            .filter_map(|(field, value)| {
+                value
+                    .as_ref()
+                    .map(|value| format!("{}={}", field, escape_field_value(value)))
+            })
+            .collect::<Vec<String>>()
+            .join(",")
+        }
+    }
+
+    // Same escaping as fields.EscapeValue in apimachinery, so names containing the
+    // separators still select exactly one value
+    fn escape_field_value(value: &str) -> String {
+        let mut escaped = String::with_capacity(value.len());
+        for character in value.chars() {
+            if matches!(character, '\\' | ',' | '=') {
+                escaped.push('\\');
+            }
+            escaped.push(character);
+        }
+        escaped
+    }
+
+    /// Deduplicated event, identified by the occurrence it describes rather than by any
+    /// single Event object.
+    #[derive(Clone, Serialize)]
+    pub struct TimelineEntry {
+        id: String,
+        event: Event,
+    }
+
+    /// The full timeline is sent once the initial list is done, after that only the entries
+    /// that changed are sent and the frontend merges them by id.
+    #[derive(Clone, Serialize)]
+    #[serde(tag = "type")]
+    pub enum EventWatchEvent {
+        Timeline { entries: Vec<TimelineEntry> },
+        Upsert { entry: Box<TimelineEntry> },
+        Remove { id: String },
+        Error { message: String },
+    }
+
+    fn occurrences(event: &Event) -> i32 {
+        event
+            .series
+            .as_ref()
+            .and_then(|series| series.count)
+            .or(event.count)
+            .unwrap_or(1)
+    }
+
+    fn first_seen(event: &Event) -> Option<DateTime<Utc>> {
+        event
+            .first_timestamp
+            .as_ref()
+            .map(|time| time.0)
+            .or_else(|| event.event_time.as_ref().map(|time| time.0))
+            .or_else(|| event.metadata.creation_timestamp.as_ref().map(|time| time.0))
+    }
+
+    fn last_seen(event: &Event) -> Option<DateTime<Utc>> {
+        event
+            .series
+            .as_ref()
+            .and_then(|series| series.last_observed_time.as_ref())
+            .map(|time| time.0)
+            .or_else(|| event.last_timestamp.as_ref().map(|time| time.0))
+            .or_else(|| first_seen(event))
+    }
+
+    type OccurrenceKey = (String, String, String, String);
+
+    // Events with the same key describe the same occurrence on the same object
+    fn occurrence_key(event: &Event) -> OccurrenceKey {
+        (
+            event
+                .involved_object
+                .uid
+                .clone()
+                .unwrap_or_else(|| event.involved_object.name.clone().unwrap_or_default()),
+            event.reason.clone().unwrap_or_default(),
+            event.type_.clone().unwrap_or_default(),
+            event.message.clone().unwrap_or_default(),
+        )
+    }
+
+    fn entry_id(key: &OccurrenceKey) -> String {
+        serde_json::to_string(key).unwrap_or_default()
+    }
+
+    /// Collapses events that describe the same occurrence on the same object into one entry,
+    /// summing their counts, and orders the result from most to least recently seen.
+    fn deduplicate(events: impl IntoIterator<Item = Event>) -> Vec<Event> {
+        // The running total is kept apart from the event, whose series count only covers itself
+        let mut merged: HashMap<OccurrenceKey, (Event, i32)> = HashMap::new();
+
+        for event in events {
+            let key = occurrence_key(&event);
+
+            match merged.get_mut(&key) {
+                Some((existing, count)) => {
+                    *count += occurrences(&event);
+                    let first = first_seen(existing).min(first_seen(&event));
+                    let last = last_seen(existing).max(last_seen(&event));
+                    if last_seen(&event) > last_seen(existing) {
+                        *existing = event;
+                    }
+                    existing.first_timestamp = first.map(Time);
+                    existing.last_timestamp = last.map(Time);
+                }
+                None => {
+                    let count = occurrences(&event);
+                    let mut event = event;
+                    event.first_timestamp = first_seen(&event).map(Time);
+                    event.last_timestamp = last_seen(&event).map(Time);
+                    merged.insert(key, (event, count));
+                }
+            }
+        }
+
+        let mut events: Vec<Event> = merged
+            .into_values()
+            .map(|(mut event, count)| {
+                event.count = Some(count);
+                if let Some(series) = event.series.as_mut() {
+                    series.count = Some(count);
+                }
+                event
+            })
+            .collect();
+        events.sort_by(|a, b| b.last_timestamp.cmp(&a.last_timestamp));
+        events
+    }
+
+    fn event_api(client: Client, namespace: &str) -> Api<Event> {
+        if namespace.is_empty() {
+            Api::all(client)
+        } else {
+            Api::namespaced(client, namespace)
+        }
+    }
+
+    #[tauri::command]
+    pub async fn list_events(
+        context: &str,
+        namespace: &str,
+        filter: EventFilter,
+        options: Option<RequestOptions>,
+    ) -> Result<Vec<Event>, SerializableKubeError> {
+        let field_selector = filter.field_selector();
+        debug!("Listing events in namespace {} matching {}", namespace, field_selector);
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        let api = event_api(client, namespace);
+
+        let params = ListParams::default().fields(&field_selector);
+        let events = scope.read(|| api.list(&params)).await.inspect_err(|err| {
+            error!("Failed to list events in namespace {}: {}", namespace, err.message);
+        })?;
+
+        let events = deduplicate(events.items);
+        info!("Found {} distinct events in namespace {}", events.len(), namespace);
+        Ok(events)
+    }
+
+    fn timeline(events: &HashMap<String, Event>) -> Vec<TimelineEntry> {
+        deduplicate(events.values().cloned())
+            .into_iter()
+            .map(|event| TimelineEntry {
+                id: entry_id(&occurrence_key(&event)),
+                event,
+            })
+            .collect()
+    }
+
+    // Merges the events that make up one occurrence again after one of them changed
+    fn timeline_change(events: &HashMap<String, Event>, key: &OccurrenceKey) -> EventWatchEvent {
+        let occurrence = events.values().filter(|event| occurrence_key(event) == *key).cloned();
+        match deduplicate(occurrence).pop() {
+            Some(event) => EventWatchEvent::Upsert {
+                entry: Box::new(TimelineEntry { id: entry_id(key), event }),
+            },
+            None => EventWatchEvent::Remove { id: entry_id(key) },
+        }
+    }
+
+    async fn run_event_watch(app_handle: tauri::AppHandle, watch_id: String, api: Api<Event>, field_selector: String) {
+        let config = watcher::Config::default().fields(&field_selector);
+        let mut stream = watcher(api, config).default_backoff().boxed();
+        let mut events: HashMap<String, Event> = HashMap::new();
+        let mut initializing: Option<HashMap<String, Event>> = None;
+        let event_name = format!("event_watch_{}", watch_id);
+
+        while let Some(event) = stream.next().await {
+            let payloads = match event {
+                Ok(watcher::Event::Init) => {
+                    initializing = Some(HashMap::new());
+                    continue;
+                }
+                Ok(watcher::Event::InitApply(event)) => {
+                    initializing
+                        .get_or_insert_with(HashMap::new)
+                        .insert(event.uid().unwrap_or_default(), event);
+                    continue;
+                }
+                Ok(watcher::Event::InitDone) => {
+                    events = initializing.take().unwrap_or_default();
+                    vec![EventWatchEvent::Timeline { entries: timeline(&events) }]
+                }
+                Ok(watcher::Event::Apply(event)) => {
+                    let key = occurrence_key(&event);
+                    let previous = events.insert(event.uid().unwrap_or_default(), event);
+                    // An updated message moves the event to another occurrence
+                    let previous_key = previous
+                        .map(|previous| occurrence_key(&previous))
+                        .filter(|previous| *previous != key);
+                    previous_key
+                        .iter()
+                        .chain(Some(&key))
+                        .map(|key| timeline_change(&events, key))
+                        .collect()
+                }
+                Ok(watcher::Event::Delete(event)) => {
+                    events.remove(&event.uid().unwrap_or_default());
+                    vec![timeline_change(&events, &occurrence_key(&event))]
+                }
+                Err(err) => {
+                    warn!("Event watch {} failed, retrying: {}", watch_id, err);
+                    vec![EventWatchEvent::Error { message: err.to_string() }]
+                }
+            };
+
+            for payload in payloads {
+                if let Err(err) = app_handle.emit(event_name.as_ref(), payload) {
+                    warn!("Failed to emit event timeline for watch {}: {}", watch_id, err);
+                }
+            }
+        }
+    }
+
+    #[tauri::command]
+    pub async fn watch_events(
+        app_handle: tauri::AppHandle,
+        context: &str,
+        namespace: &str,
+        filter: EventFilter,
+        options: Option<RequestOptions>,
+    ) -> Result<String, SerializableKubeError> {
+        let field_selector = filter.field_selector();
+        debug!("Watching events in namespace {} matching {}", namespace, field_selector);
+        let client = RequestScope::new(options.as_ref()).client(context).await?;
+        let api = event_api(client, namespace);
+
+        let watch_id = Uuid::new_v4().to_string();
+        let task = tauri::async_runtime::spawn(run_event_watch(app_handle, watch_id.clone(), api, field_selector));
+        EVENT_WATCHES
+            .lock()
+            .unwrap()
+            .get_or_insert_with(HashMap::new)
+            .insert(watch_id.clone(), task);
+
+        info!("Started event watch {} in namespace {}", watch_id, namespace);
+        Ok(watch_id)
+    }
+
+    #[tauri::command]
+    pub async fn stop_event_watch(watch_id: &str) {
+        debug!("Stopping event watch {}", watch_id);
+        if let Some(task) = EVENT_WATCHES
+            .lock()
+            .unwrap()
+            .as_mut()
+            .and_then(|watches| watches.remove(watch_id))
+        {
+            task.abort();
+            info!("Stopped event watch {}", watch_id);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use chrono::TimeZone;
+        use k8s_openapi::api::core::v1::{EventSeries, ObjectReference};
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::MicroTime;
+        use kube::api::ObjectMeta;
+
+        fn event(uid: &str, reason: &str, message: &str, count: i32, first: i64, last: i64) -> Event {
+            Event {
+                metadata: ObjectMeta {
+                    uid: Some(uid.to_string()),
+                    ..Default::default()
+                },
+                involved_object: ObjectReference {
+                    uid: Some("pod-uid".to_string()),
+                    name: Some("web-0".to_string()),
+                    ..Default::default()
+                },
+                reason: Some(reason.to_string()),
+                type_: Some("Warning".to_string()),
+                message: Some(message.to_string()),
+                count: Some(count),
+                first_timestamp: Some(Time(Utc.timestamp_opt(first, 0).unwrap())),
+                last_timestamp: Some(Time(Utc.timestamp_opt(last, 0).unwrap())),
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn merge_events_of_the_same_occurrence() {
+            let events = deduplicate(vec![
+                event("a", "BackOff", "Back-off restarting", 3, 100, 200),
+                event("b", "BackOff", "Back-off restarting", 2, 50, 300),
+            ]);
+
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].metadata.uid.as_deref(), Some("b"));
+            assert_eq!(events[0].count, Some(5));
+            assert_eq!(events[0].first_timestamp.as_ref().unwrap().0.timestamp(), 50);
+            assert_eq!(events[0].last_timestamp.as_ref().unwrap().0.timestamp(), 300);
+        }
+
+        #[test]
+        fn escape_field_selector_values() {
+            let filter = EventFilter {
+                involved_object_kind: Some("Pod".to_string()),
+                involved_object_name: Some(r"a,b=c\d".to_string()),
+                ..Default::default()
+            };
+
+            assert_eq!(
+                filter.field_selector(),
+                r"involvedObject.kind=Pod,involvedObject.name=a\,b\=c\\d"
+            );
+        }
+
+        #[test]
+        fn sum_the_series_counts_of_three_events() {
+            let events: Vec<Event> = [("a", 3, 200), ("b", 2, 300), ("c", 4, 250)]
+                .into_iter()
+                .map(|(uid, count, last)| Event {
+                    series: Some(EventSeries {
+                        count: Some(count),
+                        last_observed_time: Some(MicroTime(Utc.timestamp_opt(last, 0).unwrap())),
+                    }),
+                    ..event(uid, "BackOff", "Back-off restarting", 1, 100, last)
+                })
+                .collect();
+
+            let events = deduplicate(events);
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].metadata.uid.as_deref(), Some("b"));
+            assert_eq!(events[0].count, Some(9));
+            assert_eq!(occurrences(&events[0]), 9);
+        }
+
+        #[test]
+        fn order_by_last_seen() {
+            let events = deduplicate(vec![
+                event("a", "Pulled", "Pulled image", 1, 100, 100),
+                event("b", "BackOff", "Back-off restarting", 1, 100, 400),
+                event("c", "Started", "Started container", 1, 100, 200),
+            ]);
+
+            let reasons: Vec<_> = events.iter().map(|event| event.reason.as_deref().unwrap()).collect();
+            assert_eq!(reasons, vec!["BackOff", "Started", "Pulled"]);
+        }
+
+        #[test]
+        fn count_events_without_a_count_once() {
+            let mut first = event("a", "Killing", "Stopping container", 0, 100, 100);
+            first.count = None;
+            let mut second = first.clone();
+            second.metadata.uid = Some("b".to_string());
+
+            assert_eq!(deduplicate(vec![first, second])[0].count, Some(2));
+        }
+
+        #[test]
+        fn send_only_the_changed_occurrence() {
+            let mut events = HashMap::new();
+            events.insert("a".to_string(), event("a", "BackOff", "Back-off restarting", 3, 100, 200));
+            events.insert("b".to_string(), event("b", "BackOff", "Back-off restarting", 2, 50, 300));
+            events.insert("c".to_string(), event("c", "Pulled", "Pulled image", 1, 100, 100));
+            let key = occurrence_key(&events["a"]);
+
+            match timeline_change(&events, &key) {
+                EventWatchEvent::Upsert { entry } => {
+                    assert_eq!(entry.id, entry_id(&key));
+                    assert_eq!(entry.event.count, Some(5));
+                }
+                _ => panic!("expected an upsert"),
+            }
+
+            events.remove("a");
+            events.remove("b");
+            assert!(matches!(timeline_change(&events, &key), EventWatchEvent::Remove { id } if id == entry_id(&key)));
+        }
+    }
+}
+
+pub mod nodes {
+    use super::client::{ErrorKind, RequestOptions, RequestScope, SerializableKubeError};
+    use futures::future::join_all;
+    use k8s_openapi::api::core::v1::{Node, Pod};
+    use kube::api::{Api, DeleteParams, EvictParams, ListParams};
+    use kube::{Client, ResourceExt};
+    use serde::{Deserialize, Serialize};
+    use std::time::Duration;
+    use tauri::Emitter;
+    use tokio::time::{sleep, Instant};
+    use tracing::{debug, error, info, warn};
+
+    // Annotation the kubelet puts on the API representation of static pods
+    const MIRROR_POD_ANNOTATION: &str = "kubernetes.io/config.mirror";
+    const EVICTION_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+    const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(300);
+
+    #[derive(Clone, Debug, Default, Deserialize)]
+    pub struct DrainOptions {
+        pub grace_period_seconds: Option<u32>,
+        pub timeout_seconds: Option<u64>,
+    }
+
+    #[derive(Clone, Debug, Serialize)]
+    #[serde(tag = "status")]
+    pub enum DrainPodStatus {
+        Skipped { reason: String },
+        Evicting,
+        Blocked { message: String },
+        Evicted,
+        Deleted,
+        Failed { message: String },
+    }
+
+    #[derive(Clone, Debug, Serialize)]
+    pub struct DrainProgress {
+        namespace: String,
+        pod: String,
+        #[serde(flatten)]
+        status: DrainPodStatus,
+    }
+
+    #[derive(Clone, Debug, Default, Serialize)]
+    pub struct DrainResult {
+        evicted: Vec<String>,
+        skipped: Vec<String>,
+        failed: Vec<String>,
+    }
+
+    #[tauri::command]
+    pub async fn list_nodes(
+        context: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<Vec<Node>, SerializableKubeError> {
+        debug!("Listing nodes for context {}", context);
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        let node_api: Api<Node> = Api::all(client);
+
+        let params = ListParams::default();
+        let nodes = scope.read(|| node_api.list(&params)).await.inspect_err(|err| {
+            error!("Failed to list nodes: {}", err.message);
+        })?;
+
+        info!("Found {} nodes", nodes.items.len());
+        Ok(nodes.items)
+    }
+
+    #[tauri::command]
+    pub async fn cordon_node(
+        context: &str,
+        name: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<Node, SerializableKubeError> {
+        debug!("Cordoning node {}", name);
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        cordon(client, name, &scope).await
+    }
+
+    async fn cordon(client: Client, name: &str, scope: &RequestScope) -> Result<Node, SerializableKubeError> {
+        let node_api: Api<Node> = Api::all(client);
+
+        let node = scope.write(|| node_api.cordon(name)).await.inspect_err(|err| {
+            error!("Failed to cordon node {}: {}", name, err.message);
+        })?;
+
+        info!("Successfully cordoned node {}", name);
+        Ok(node)
+    }
+
+    #[tauri::command]
+    pub async fn uncordon_node(
+        context: &str,
+        name: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<Node, SerializableKubeError> {
+        debug!("Uncordoning node {}", name);
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        let node_api: Api<Node> = Api::all(client);
+
+        let node = scope.write(|| node_api.uncordon(name)).await.inspect_err(|err| {
+            error!("Failed to uncordon node {}: {}", name, err.message);
+        })?;
+
+        info!("Successfully uncordoned node {}", name);
+        Ok(node)
+    }
+
+    fn skip_reason(pod: &Pod) -> Option<String> {
+        if pod.annotations().contains_key(MIRROR_POD_ANNOTATION) {
+            return Some("Mirror pod".to_string());
+        }
+
+        if pod.owner_references().iter().any(|owner| owner.kind == "DaemonSet") {
+            return Some("Managed by a DaemonSet".to_string());
+        }
+
+        None
+    }
+
+    async fn evict_pod(
+        app_handle: &tauri::AppHandle,
+        node: &str,
+        pod_api: Api<Pod>,
+        pod: &Pod,
+        options: &DrainOptions,
+        deadline: Instant,
+        scope: &RequestScope,
+    ) -> DrainPodStatus {
+        let name = pod.name_any();
+        let namespace = pod.namespace().unwrap_or_default();
+        let report = |status: DrainPodStatus| {
+            let progress = DrainProgress {
+                namespace: namespace.clone(),
+                pod: name.clone(),
+                status: status.clone(),
+            };
+            if let Err(err) = app_handle.emit(format!("node_drain_{}", node).as_ref(), progress) {
+                warn!("Failed to emit drain progress for pod {}/{}: {}", namespace, name, err);
+            }
+            status
+        };
+
+        let params = EvictParams {
+            delete_options: options
+                .grace_period_seconds
+                .map(|seconds| DeleteParams::default().grace_period(seconds)),
+            ..Default::default()
+        };
+
+        report(DrainPodStatus::Evicting);
+        loop {
+            match scope.write(|| pod_api.evict(&name, &params)).await {
+                Ok(_) => break,
+                Err(err) if err.kind == ErrorKind::NotFound => return report(DrainPodStatus::Deleted),
+                // A PodDisruptionBudget currently does not allow the pod to be disrupted
+                Err(err) if err.kind == ErrorKind::TooManyRequests => {
+                    if Instant::now() + EVICTION_RETRY_INTERVAL > deadline {
+                        return report(DrainPodStatus::Failed {
+                            message: format!("Timed out waiting for eviction: {}", err.message),
+                        });
+                    }
+                    report(DrainPodStatus::Blocked { message: err.message });
+                    sleep(EVICTION_RETRY_INTERVAL).await;
+                }
+                Err(err) => return report(DrainPodStatus::Failed { message: err.message }),
+            }
+        }
+
+        report(DrainPodStatus::Evicted);
+
+        // The pod is only gone once it is deleted, or replaced by a pod with the same name
+        while Instant::now() < deadline {
+            match scope.read(|| pod_api.get_opt(&name)).await {
+                Ok(Some(current)) if current.uid() == pod.uid() => {
+                    sleep(Duration::from_secs(1)).await;
+                }
+                Ok(_) => return report(DrainPodStatus::Deleted),
+                Err(err) => return report(DrainPodStatus::Failed { message: err.message }),
+            }
+        }
+
+        report(DrainPodStatus::Failed {
+            message: "Timed out waiting for the pod to be deleted".to_string(),
+        })
+    }
+
+    #[tauri::command]
+    pub async fn drain_node(
+        app_handle: tauri::AppHandle,
+        context: &str,
+        name: &str,
+        options: DrainOptions,
+        request_options: Option<RequestOptions>,
+    ) -> Result<DrainResult, SerializableKubeError> {
+        debug!("Draining node {}", name);
+        let scope = RequestScope::new(request_options.as_ref());
+        let client = scope.client(context).await?;
+        let deadline = Instant::now()
+            + options
+                .timeout_seconds
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_DRAIN_TIMEOUT);
+
+        cordon(client.clone(), name, &scope).await?;
+
+        let all_pods: Api<Pod> = Api::all(client.clone());
+        let params = ListParams::default().fields(&format!("spec.nodeName={}", name));
+        let pods = scope.read(|| all_pods.list(&params)).await.inspect_err(|err| {
+            error!("Failed to list pods on node {}: {}", name, err.message);
+        })?;
+
+        let mut result = DrainResult::default();
+        let mut evictions = Vec::new();
+        for pod in pods.items.iter() {
+            let qualified_name = format!("{}/{}", pod.namespace().unwrap_or_default(), pod.name_any());
+            if let Some(reason) = skip_reason(pod) {
+                debug!("Skipping pod {} while draining node {}: {}", qualified_name, name, reason);
+                let progress = DrainProgress {
+                    namespace: pod.namespace().unwrap_or_default(),
+                    pod: pod.name_any(),
+                    status: DrainPodStatus::Skipped { reason },
+                };
+                let _ = app_handle.emit(format!("node_drain_{}", name).as_ref(), progress);
+                result.skipped.push(qualified_name);
+                continue;
+            }
+
+            let pod_api: Api<Pod> = Api::namespaced(client.clone(), &pod.namespace().unwrap_or_default());
+            let app_handle = &app_handle;
+            let options = &options;
+            let scope = &scope;
+            evictions.push(async move {
+                let status = evict_pod(app_handle, name, pod_api, pod, options, deadline, scope).await;
+                (qualified_name, status)
+            });
+        }
+
+        for (qualified_name, status) in join_all(evictions).await {
+            match status {
+                DrainPodStatus::Evicted | DrainPodStatus::Deleted => result.evicted.push(qualified_name),
+                _ => result.failed.push(qualified_name),
+            }
+        }
+
+        if result.failed.is_empty() {
+            info!("Successfully drained node {}, evicted {} pods", name, result.evicted.len());
+        } else {
+            warn!("Draining node {} left {} pods behind", name, result.failed.len());
+        }
+        Ok(result)
+    }
+}
+
+pub mod scale {
+    use super::client::{ErrorKind, RequestOptions, RequestScope, SerializableKubeError};
+    use super::dynamic::ResourceType;
+    use super::rollout::track_rollout_if_requested;
+    use kube::api::{Api, DynamicObject, Patch, PatchParams};
+    use kube::core::GroupVersionKind;
+    use kube::discovery::{self, Scope};
+    use serde::Serialize;
+    use serde_json::json;
+    use std::time::Duration;
+    use tokio::time::{sleep, Instant};
+    use tracing::{debug, error, info, warn};
+
+    const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(300);
+
+    #[derive(Clone, Debug, Serialize)]
+    pub struct ScaleResult {
+        spec_replicas: i32,
+        status_replicas: i32,
+        ready_replicas: Option<i32>,
+        reached: bool,
+    }
+
+    // Built-in kinds whose readyReplicas is omitted by the API server while it is zero
+    const OMIT_ZERO_READY_REPLICAS: [&str; 3] = ["Deployment", "StatefulSet", "ReplicaSet"];
+
+    /// Custom kinds with a scale subresource often never publish readyReplicas, so it is
+    /// only assumed to be zero for the built-in kinds. `None` makes the wait fall back to
+    /// the replicas in the scale status.
+    fn ready_replicas(resource_type: &ResourceType, object: &DynamicObject) -> Option<i32> {
+        let status = object.data.get("status")?;
+        match status.get("readyReplicas").and_then(|ready| ready.as_i64()) {
+            Some(ready) => Some(ready as i32),
+            None if resource_type.group == "apps"
+                && OMIT_ZERO_READY_REPLICAS.contains(&resource_type.kind.as_str()) =>
+            {
+                Some(0)
+            }
+            None => None,
+        }
+    }
+
+    #[tauri::command]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn scale_resource(
+        app_handle: tauri::AppHandle,
+        context: &str,
+        resource_type: ResourceType,
+        namespace: &str,
+        name: &str,
+        replicas: i32,
+        wait: Option<bool>,
+        timeout_seconds: Option<u64>,
+        rollout_tracking_id: Option<String>,
+        options: Option<RequestOptions>,
+    ) -> Result<ScaleResult, SerializableKubeError> {
+        debug!("Scaling {} {}/{} to {} replicas", resource_type.kind, namespace, name, replicas);
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+
+        let gvk = GroupVersionKind::gvk(&resource_type.group, &resource_type.version, &resource_type.kind);
+        let (api_resource, capabilities) = scope.read(|| discovery::pinned_kind(&client, &gvk)).await.inspect_err(|err| {
+            error!("Failed to discover {}/{} {}: {}", gvk.group, gvk.version, gvk.kind, err.message);
+        })?;
+
+        if !capabilities.subresources.iter().any(|(subresource, _)| subresource.plural == "scale") {
+            return Err(SerializableKubeError::new(
+                ErrorKind::Unsupported,
+                "NotScalable",
+                format!("{} does not have a scale subresource", resource_type.kind),
+            ));
+        }
+
+        let api: Api<DynamicObject> = match capabilities.scope {
+            Scope::Namespaced => Api::namespaced_with(client.clone(), namespace, &api_resource),
+            Scope::Cluster => Api::all_with(client.clone(), &api_resource),
+        };
+
+        let params = PatchParams::default();
+        let patch = Patch::Merge(json!({ "spec": { "replicas": replicas } }));
+        let mut scale = scope.write(|| api.patch_scale(name, &params, &patch)).await.inspect_err(|err| {
+            error!("Failed to scale {} {}/{}: {}", resource_type.kind, namespace, name, err.message);
+        })?;
+        info!("Scaled {} {}/{} to {} replicas", resource_type.kind, namespace, name, replicas);
+        track_rollout_if_requested(&app_handle, client, namespace, &resource_type.rollout_kind(), name, rollout_tracking_id);
+
+        let mut ready = None;
+        let mut reached = false;
+        if wait.unwrap_or(false) {
+            let deadline = Instant::now()
+                + timeout_seconds
+                    .map(Duration::from_secs)
+                    .unwrap_or(DEFAULT_WAIT_TIMEOUT);
+
+            // The request deadline applies to each poll; cancelling also stops the wait
+            scope
+                .cancellable(async {
+                    while Instant::now() < deadline {
+                        scale = scope.read(|| api.get_scale(name)).await?;
+                        let object = scope.read(|| api.get(name)).await?;
+                        ready = ready_replicas(&resource_type, &object);
+
+                        let current = scale.status.as_ref().map(|status| status.replicas).unwrap_or(0);
+                        if current == replicas && ready.unwrap_or(current) == replicas {
+                            reached = true;
+                            break;
+                        }
+                        sleep(Duration::from_secs(1)).await;
+                    }
+                    Ok(())
+                })
+                .await?;
+
+            if !reached {
+                warn!("{} {}/{} did not reach {} replicas in time", resource_type.kind, namespace, name, replicas);
+            }
+        }
+
+        Ok(ScaleResult {
+            spec_replicas: scale.spec.and_then(|spec| spec.replicas).unwrap_or(replicas),
+            status_replicas: scale.status.map(|status| status.replicas).unwrap_or(0),
+            ready_replicas: ready,
+            reached,
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn resource_type(group: &str, kind: &str) -> ResourceType {
+            ResourceType {
+                group: group.to_string(),
+                version: "v1".to_string(),
+                kind: kind.to_string(),
+                plural: None,
+                namespaced: None,
+            }
+        }
+
+        fn object(data: serde_json::Value) -> DynamicObject {
+            DynamicObject {
+                types: None,
+                metadata: Default::default(),
+                data,
+            }
+        }
+
+        #[test]
+        fn read_ready_replicas_when_published() {
+            let object = object(json!({"status": {"replicas": 3, "readyReplicas": 2}}));
+            assert_eq!(ready_replicas(&resource_type("example.com", "Widget"), &object), Some(2));
+        }
+
+        #[test]
+        fn default_ready_replicas_to_zero_for_built_in_kinds() {
+            let object = object(json!({"status": {"replicas": 3}}));
+            assert_eq!(ready_replicas(&resource_type("apps", "Deployment"), &object), Some(0));
+            assert_eq!(ready_replicas(&resource_type("example.com", "Widget"), &object), None);
+        }
+
+        #[test]
+        fn leave_ready_replicas_unknown_without_a_status() {
+            let object = object(json!({"spec": {"replicas": 3}}));
+            assert_eq!(ready_replicas(&resource_type("apps", "Deployment"), &object), None);
+        }
+    }
+}
+
+pub mod rollout {
+    use super::client::{ErrorKind, RequestOptions, RequestScope, SerializableKubeError};
+    use super::diff::{diff_values, FieldChange};
+    use futures::StreamExt;
+    use k8s_openapi::api::apps::v1::{ControllerRevision, Deployment, ReplicaSet, StatefulSet};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+    use kube::api::{Api, ListParams, Patch, PatchParams, PostParams};
+    use kube::runtime::watcher::{self, watch_object};
+    use kube::runtime::WatchStreamExt;
+    use kube::{Client, ResourceExt};
+    use serde::{Deserialize, Serialize};
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use tauri::async_runtime::JoinHandle;
+    use tauri::Emitter;
+    use tokio::time::{timeout_at, Instant};
+    use tracing::{debug, error, info, warn};
+
+    const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+    const CHANGE_CAUSE_ANNOTATION: &str = "kubernetes.io/change-cause";
+    // Label the deployment controller adds to the template of every ReplicaSet it owns
+    const POD_TEMPLATE_HASH_LABEL: &str = "pod-template-hash";
+    const DEFAULT_TRACKING_TIMEOUT: Duration = Duration::from_secs(600);
+
+    static ROLLOUT_TRACKERS: Mutex<Option<HashMap<String, JoinHandle<()>>>> = Mutex::new(None);
+
+    #[derive(Clone, Copy, Debug, Deserialize)]
+    pub enum RolloutKind {
+        Deployment,
+        StatefulSet,
+    }
+
+    impl RolloutKind {
+        /// Maps the kind of an object to the rollout it can be tracked with, if any.
+        pub(crate) fn from_kind(kind: &str) -> Option<Self> {
+            match kind.to_lowercase().as_str() {
+                "deployment" => Some(RolloutKind::Deployment),
+                "statefulset" => Some(RolloutKind::StatefulSet),
+                _ => None,
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize)]
+    #[serde(tag = "phase")]
+    pub enum RolloutPhase {
+        Progressing,
+        Complete,
+        Failed { reason: String },
+        TimedOut,
+    }
+
+    #[derive(Clone, Debug, Serialize)]
+    pub struct RolloutProgress {
+        #[serde(flatten)]
+        phase: RolloutPhase,
+        message: String,
+        generation: Option<i64>,
+        observed_generation: Option<i64>,
+        desired: i32,
+        updated: i32,
+        ready: i32,
+        available: i32,
+    }
+
+    #[derive(Clone, Debug, Serialize)]
+    pub struct RolloutRevision {
+        revision: i64,
+        name: String,
+        created: Option<Time>,
+        change_cause: Option<String>,
+        current: bool,
+        template: Value,
+    }
+
+    fn selector_for(labels: Option<std::collections::BTreeMap<String, String>>) -> String {
+        labels
+            .unwrap_or_default()
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    async fn deployment_revisions(
+        client: Client,
+        namespace: &str,
+        name: &str,
+        scope: &RequestScope,
+    ) -> Result<Vec<RolloutRevision>, SerializableKubeError> {
+        let deployment_api = Api::<Deployment>::namespaced(client.clone(), namespace);
+        let deployment = scope.read(|| deployment_api.get(name)).await?;
+        let current_revision = deployment.annotations().get(REVISION_ANNOTATION).cloned();
+        let selector = selector_for(
+            deployment
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.selector.match_labels.clone()),
+        );
+
+        let params = ListParams::default().labels(&selector);
+        let replica_sets_api = Api::<ReplicaSet>::namespaced(client, namespace);
+        let replica_sets = scope.read(|| replica_sets_api.list(&params)).await?;
+
+        Ok(replica_sets
+            .items
+            .into_iter()
+            .filter(|rs| rs.owner_references().iter().any(|owner| Some(&owner.uid) == deployment.metadata.uid.as_ref()))
+            .filter_map(|rs| {
+                let revision_annotation = rs.annotations().get(REVISION_ANNOTATION)?.clone();
+                let mut template = serde_json::to_value(rs.spec.as_ref()?.template.as_ref()?).ok()?;
+                if let Some(labels) = template.pointer_mut("/metadata/labels").and_then(Value::as_object_mut) {
+                    labels.remove(POD_TEMPLATE_HASH_LABEL);
+                }
+
+                Some(RolloutRevision {
+                    revision: revision_annotation.parse().ok()?,
+                    name: rs.name_any(),
+                    created: rs.metadata.creation_timestamp.clone(),
+                    change_cause: rs.annotations().get(CHANGE_CAUSE_ANNOTATION).cloned(),
+                    current: current_revision.as_ref() == Some(&revision_annotation),
+                    template,
+                })
+            })
+            .collect())
+    }
+
+    async fn statefulset_revisions(
+        client: Client,
+        namespace: &str,
+        name: &str,
+        scope: &RequestScope,
+    ) -> Result<Vec<RolloutRevision>, SerializableKubeError> {
+        let statefulset_api = Api::<StatefulSet>::namespaced(client.clone(), namespace);
+        let statefulset = scope.read(|| statefulset_api.get(name)).await?;
+        let update_revision = statefulset
+            .status
+            .as_ref()
+            .and_then(|status| status.update_revision.clone());
+        let selector = selector_for(
+            statefulset
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.selector.match_labels.clone()),
+        );
+
+        let params = ListParams::default().labels(&selector);
+        let revisions_api = Api::<ControllerRevision>::namespaced(client, namespace);
+        let revisions = scope.read(|| revisions_api.list(&params)).await?;
+
+        Ok(revisions
+            .items
+            .into_iter()
+            .filter(|cr| cr.owner_references().iter().any(|owner| Some(&owner.uid) == statefulset.metadata.uid.as_ref()))
+            .map(|cr| {
+                // The revision data is the strategic merge patch that restores the template
+                let mut template = cr
+                    .data
+                    .as_ref()
+                    .and_then(|data| data.0.pointer("/spec/template"))
+                    .cloned()
+                    .unwrap_or(Value::Null);
+                if let Some(template) = template.as_object_mut() {
+                    template.remove("$patch");
+                }
+
+                RolloutRevision {
+                    revision: cr.revision,
+                    name: cr.name_any(),
+                    created: cr.metadata.creation_timestamp.clone(),
+                    change_cause: cr.annotations().get(CHANGE_CAUSE_ANNOTATION).cloned(),
+                    current: update_revision.as_deref() == Some(cr.name_any().as_str()),
+                    template,
+                }
+            })
+            .collect())
+    }
+
+    async fn revisions_for(
+        context: &str,
+        namespace: &str,
+        kind: RolloutKind,
+        name: &str,
+        scope: &RequestScope,
+    ) -> Result<Vec<RolloutRevision>, SerializableKubeError> {
+        let client = scope.client(context).await?;
+        let mut revisions = match kind {
+            RolloutKind::Deployment => deployment_revisions(client, namespace, name, scope).await,
+            RolloutKind::StatefulSet => statefulset_revisions(client, namespace, name, scope).await,
+        }
+        .inspect_err(|err| {
+            error!("Failed to get rollout history of {:?} {}/{}: {}", kind, namespace, name, err.message);
+        })?;
+
+        revisions.sort_by_key(|revision| revision.revision);
+        Ok(revisions)
+    }
+
+    fn revision_not_found(revision: i64) -> SerializableKubeError {
+        SerializableKubeError::new(
+            ErrorKind::NotFound,
+            "RevisionNotFound",
+            format!("Revision {} not found", revision),
+        )
+    }
+
+    #[tauri::command]
+    pub async fn get_rollout_history(
+        context: &str,
+        namespace: &str,
+        kind: RolloutKind,
+        name: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<Vec<RolloutRevision>, SerializableKubeError> {
+        debug!("Getting rollout history of {:?} {}/{}", kind, namespace, name);
+        let scope = RequestScope::new(options.as_ref());
+        let revisions = revisions_for(context, namespace, kind, name, &scope).await?;
+
+        info!("Found {} revisions of {:?} {}/{}", revisions.len(), kind, namespace, name);
+        Ok(revisions)
+    }
+
+    #[tauri::command]
+    pub async fn diff_rollout_revisions(
+        context: &str,
+        namespace: &str,
+        kind: RolloutKind,
+        name: &str,
+        from_revision: i64,
+        to_revision: i64,
+        options: Option<RequestOptions>,
+    ) -> Result<Vec<FieldChange>, SerializableKubeError> {
+        debug!(
+            "Diffing revisions {} and {} of {:?} {}/{}",
+            from_revision, to_revision, kind, namespace, name
+        );
+        let scope = RequestScope::new(options.as_ref());
+        let revisions = revisions_for(context, namespace, kind, name, &scope).await?;
+        let find = |revision: i64| {
+            revisions
+                .iter()
+                .find(|r| r.revision == revision)
+                .ok_or_else(|| revision_not_found(revision))
+        };
+
+        Ok(diff_values(&find(from_revision)?.template, &find(to_revision)?.template))
+    }
+
+    #[tauri::command]
+    pub async fn rollback_rollout(
+        context: &str,
+        namespace: &str,
+        kind: RolloutKind,
+        name: &str,
+        revision: i64,
+        options: Option<RequestOptions>,
+    ) -> Result<bool, SerializableKubeError> {
+        debug!("Rolling back {:?} {}/{} to revision {}", kind, namespace, name, revision);
+        let scope = RequestScope::new(options.as_ref());
+        let revisions = revisions_for(context, namespace, kind, name, &scope).await?;
+        let target = revisions
+            .into_iter()
+            .find(|r| r.revision == revision)
+            .ok_or_else(|| revision_not_found(revision))?;
+
+        let client = scope.client(context).await?;
+        let result = match kind {
+            RolloutKind::Deployment => {
+                // Swap the whole template so fields added after the target revision are dropped
+                let api: Api<Deployment> = Api::namespaced(client, namespace);
+                match scope.read(|| api.get(name)).await {
+                    Ok(mut deployment) => {
+                        if let Some(spec) = deployment.spec.as_mut() {
+                            spec.template = serde_json::from_value(target.template)
+                                .map_err(|err| SerializableKubeError::new(
+                                    ErrorKind::Invalid,
+                                    "InvalidRevision",
+                                    err.to_string(),
+                                ))?;
+                        }
+                        let params = PostParams::default();
+                        scope.write(|| api.replace(name, &params, &deployment)).await.map(|_| ())
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+            RolloutKind::StatefulSet => {
+                let api: Api<StatefulSet> = Api::namespaced(client, namespace);
+                let mut template = target.template;
+                if let Some(template) = template.as_object_mut() {
+                    template.insert("$patch".to_string(), json!("replace"));
+                }
+                let params = PatchParams::default();
+                let patch = Patch::Strategic(json!({ "spec": { "template": template } }));
+                scope.write(|| api.patch(name, &params, &patch)).await.map(|_| ())
+            }
+        };
+
+        match result {
+            Ok(_) => {
+                info!("Rolled back {:?} {}/{} to revision {}", kind, namespace, name, revision);
+                Ok(true)
+            }
+            Err(err) => {
+                error!("Failed to roll back {:?} {}/{}: {}", kind, namespace, name, err.message);
+                Err(err)
+            }
+        }
+    }
+
+    async fn set_paused(
+        context: &str,
+        namespace: &str,
+        kind: RolloutKind,
+        name: &str,
+        paused: bool,
+        options: Option<RequestOptions>,
+    ) -> Result<bool, SerializableKubeError> {
+        let RolloutKind::Deployment = kind else {
+            return Err(SerializableKubeError::new(
+                ErrorKind::Unsupported,
+                "NotSupported",
+                format!("Pausing a {:?} rollout is not supported", kind),
+            ));
+        };
+
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        let api: Api<Deployment> = Api::namespaced(client, namespace);
+        let params = PatchParams::default();
+        let patch = Patch::Merge(json!({ "spec": { "paused": paused } }));
+
+        match scope.write(|| api.patch(name, &params, &patch)).await {
+            Ok(_) => {
+                info!("Set paused to {} for deployment {}/{}", paused, namespace, name);
+                Ok(true)
+            }
+            Err(err) => {
+                error!("Failed to set paused to {} for deployment {}/{}: {}", paused, namespace, name, err.message);
+                Err(err)
+            }
+        }
+    }
+
+    #[tauri::command]
+    pub async fn pause_rollout(
+        context: &str,
+        namespace: &str,
+        kind: RolloutKind,
+        name: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<bool, SerializableKubeError> {
+        debug!("Pausing rollout of {:?} {}/{}", kind, namespace, name);
+        set_paused(context, namespace, kind, name, true, options).await
+    }
+
+    #[tauri::command]
+    pub async fn resume_rollout(
+        context: &str,
+        namespace: &str,
+        kind: RolloutKind,
+        name: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<bool, SerializableKubeError> {
+        debug!("Resuming rollout of {:?} {}/{}", kind, namespace, name);
+        set_paused(context, namespace, kind, name, false, options).await
+    }
+
+    /// Evaluates a deployment the same way `kubectl rollout status` does.
+    fn deployment_progress(deployment: &Deployment) -> RolloutProgress {
+        let spec = deployment.spec.clone().unwrap_or_default();
+        let status = deployment.status.clone().unwrap_or_default();
+        let desired = spec.replicas.unwrap_or(1);
+        let updated = status.updated_replicas.unwrap_or(0);
+        let available = status.available_replicas.unwrap_or(0);
+        let replicas = status.replicas.unwrap_or(0);
+
+        let progressing = status
+            .conditions
+            .unwrap_or_default()
+            .into_iter()
+            .find(|condition| condition.type_ == "Progressing");
+
+        let observed = deployment.metadata.generation <= status.observed_generation;
+        let (phase, message) = if !observed {
+            (RolloutPhase::Progressing, "Waiting for the rollout to be observed".to_string())
+        } else if progressing
+            .as_ref()
+            .is_some_and(|condition| condition.reason.as_deref() == Some("ProgressDeadlineExceeded"))
+        {
+            (
+                RolloutPhase::Failed {
+                    reason: "ProgressDeadlineExceeded".to_string(),
+                },
+                progressing.and_then(|condition| condition.message).unwrap_or_default(),
+            )
+        } else if updated < desired {
+            (
+                RolloutPhase::Progressing,
+                format!("{} of {} new replicas have been updated", updated, desired),
+            )
+        } else if replicas > updated {
+            (
+                RolloutPhase::Progressing,
+                format!("{} old replicas are pending termination", replicas - updated),
+            )
+        } else if available < updated {
+            (
+                RolloutPhase::Progressing,
+                format!("{} of {} updated replicas are available", available, updated),
+            )
+        } else {
+            (RolloutPhase::Complete, "Successfully rolled out".to_string())
+        };
+
+        RolloutProgress {
+            phase,
+            message,
+            generation: deployment.metadata.generation,
+            observed_generation: status.observed_generation,
+            desired,
+            updated,
+            ready: status.ready_replicas.unwrap_or(0),
+            available,
+        }
+    }
+
+    /// Evaluates a statefulset the same way `kubectl rollout status` does.
+    fn statefulset_progress(statefulset: &StatefulSet) -> RolloutProgress {
+        let spec = statefulset.spec.clone().unwrap_or_default();
+        let status = statefulset.status.clone().unwrap_or_default();
+        let desired = spec.replicas.unwrap_or(1);
+        let updated = status.updated_replicas.unwrap_or(0);
+        let ready = status.ready_replicas.unwrap_or(0);
+        let strategy = spec.update_strategy.unwrap_or_default();
+        let partition = strategy
+            .rolling_update
+            .and_then(|rolling_update| rolling_update.partition)
+            .unwrap_or(0);
+
+        let observed = statefulset.metadata.generation <= status.observed_generation;
+        let (phase, message) = if strategy.type_.as_deref() == Some("OnDelete") {
+            (
+                RolloutPhase::Failed {
+                    reason: "OnDeleteStrategy".to_string(),
+                },
+                "Rollout status is only available for the RollingUpdate strategy".to_string(),
+            )
+        } else if !observed {
+            (RolloutPhase::Progressing, "Waiting for the rollout to be observed".to_string())
+        } else if ready < desired {
+            (
+                RolloutPhase::Progressing,
+                format!("{} of {} replicas are ready", ready, desired),
+            )
+        } else if partition > 0 {
+            if updated < desired - partition {
+                (
+                    RolloutPhase::Progressing,
+                    format!("{} of {} partitioned replicas are updated", updated, desired - partition),
+                )
+            } else {
+                (RolloutPhase::Complete, "Partitioned rollout complete".to_string())
+            }
+        } else if status.update_revision != status.current_revision {
+            (
+                RolloutPhase::Progressing,
+                format!("{} of {} replicas are updated", updated, desired),
+            )
+        } else {
+            (RolloutPhase::Complete, "Successfully rolled out".to_string())
+        };
+
+        RolloutProgress {
+            phase,
+            message,
+            generation: statefulset.metadata.generation,
+            observed_generation: status.observed_generation,
+            desired,
+            updated,
+            ready,
+            available: status.available_replicas.unwrap_or(0),
+        }
+    }
+
+    async fn follow_rollout<K>(
+        app_handle: &tauri::AppHandle,
+        api: Api<K>,
+        name: &str,
+        event_name: &str,
+        deadline: Instant,
+        evaluate: fn(&K) -> RolloutProgress,
+    ) where
+        K: kube::Resource + Clone + serde::de::DeserializeOwned + std::fmt::Debug + Send + 'static,
+    {
+        let emit = |progress: &RolloutProgress| {
+            if let Err(err) = app_handle.emit(event_name, progress.clone()) {
+                warn!("Failed to emit rollout progress for {}: {}", name, err);
+            }
+        };
+
+        let mut stream = watch_object(api, name).default_backoff().boxed();
+        let mut last: Option<RolloutProgress> = None;
+        loop {
+            let progress = match timeout_at(deadline, stream.next()).await {
+                Ok(Some(Ok(Some(object)))) => evaluate(&object),
+                Ok(Some(Ok(None))) => RolloutProgress {
+                    phase: RolloutPhase::Failed {
+                        reason: "NotFound".to_string(),
+                    },
+                    message: format!("{} no longer exists", name),
+                    ..last.clone().unwrap_or_else(empty_progress)
+                },
+                Ok(Some(Err(err))) => {
+                    let kind = watch_error_kind(&err);
+                    if !matches!(kind, ErrorKind::Auth | ErrorKind::Forbidden | ErrorKind::NotFound) {
+                        warn!("Watching rollout of {} failed, retrying: {}", name, err);
+                        continue;
+                    }
+                    error!("Watching rollout of {} failed: {}", name, err);
+                    RolloutProgress {
+                        phase: RolloutPhase::Failed {
+                            reason: format!("{:?}", kind),
+                        },
+                        message: err.to_string(),
+                        ..last.clone().unwrap_or_else(empty_progress)
+                    }
+                }
+                Ok(None) => return,
+                Err(_) => RolloutProgress {
+                    phase: RolloutPhase::TimedOut,
+                    message: "Timed out waiting for the rollout to finish".to_string(),
+                    ..last.clone().unwrap_or_else(empty_progress)
+                },
+            };
+
+            emit(&progress);
+            if progress.phase != RolloutPhase::Progressing {
+                info!("Rollout of {} finished: {:?}", name, progress.phase);
+                return;
+            }
+            last = Some(progress);
+        }
+    }
+
+    // The backoff only helps with errors that go away, so the kind decides whether to keep going
+    fn watch_error_kind(err: &watcher::Error) -> ErrorKind {
+        match err {
+            watcher::Error::InitialListFailed(err)
+            | watcher::Error::WatchStartFailed(err)
+            | watcher::Error::WatchFailed(err) => ErrorKind::of(err),
+            watcher::Error::WatchError(response) => ErrorKind::from_status(response.code),
+            watcher::Error::NoResourceVersion => ErrorKind::Unknown,
+        }
+    }
+
+    fn empty_progress() -> RolloutProgress {
+        RolloutProgress {
+            phase: RolloutPhase::Progressing,
+            message: String::new(),
+            generation: None,
+            observed_generation: None,
+            desired: 0,
+            updated: 0,
+            ready: 0,
+            available: 0,
+        }
+    }
+
+    /// Follows the rollout of a deployment or statefulset until it completes, fails or times
+    /// out, emitting every change as a `rollout_status_{tracking_id}` event. Callers generate
+    /// the tracking id so they can listen before the rollout starts.
+    pub(crate) fn track_rollout(
+        app_handle: tauri::AppHandle,
+        client: Client,
+        namespace: &str,
+        kind: RolloutKind,
+        name: &str,
+        tracking_id: String,
+        timeout: Option<Duration>,
+    ) {
+        debug!("Tracking rollout {} of {:?} {}/{}", tracking_id, kind, namespace, name);
+        let deadline = Instant::now() + timeout.unwrap_or(DEFAULT_TRACKING_TIMEOUT);
+        let event_name = format!("rollout_status_{}", tracking_id);
+        let name = name.to_string();
+        let namespace = namespace.to_string();
+        let thread_tracking_id = tracking_id.clone();
+
+        let task = tauri::async_runtime::spawn(async move {
+            match kind {
+                RolloutKind::Deployment => {
+                    let api: Api<Deployment> = Api::namespaced(client, &namespace);
+                    follow_rollout(&app_handle, api, &name, &event_name, deadline, deployment_progress).await
+                }
+                RolloutKind::StatefulSet => {
+                    let api: Api<StatefulSet> = Api::namespaced(client, &namespace);
+                    follow_rollout(&app_handle, api, &name, &event_name, deadline, statefulset_progress).await
+                }
+            }
+
+            ROLLOUT_TRACKERS
+                .lock()
+                .unwrap()
+                .as_mut()
+                .and_then(|trackers| trackers.remove(&thread_tracking_id));
+        });
+
+        ROLLOUT_TRACKERS
+            .lock()
+            .unwrap()
+            .get_or_insert_with(HashMap::new)
+            .insert(tracking_id, task);
+    }
+
+    /// Starts tracking when the caller of a restart, scale or apply command opted into it.
+    pub(crate) fn track_rollout_if_requested(
+        app_handle: &tauri::AppHandle,
+        client: Client,
+        namespace: &str,
+        kind: &str,
+        name: &str,
+        tracking_id: Option<String>,
+    ) {
+        let Some(tracking_id) = tracking_id else {
+            return;
+        };
+
+        match RolloutKind::from_kind(kind) {
+            Some(kind) => track_rollout(app_handle.clone(), client, namespace, kind, name, tracking_id, None),
+            None => warn!("Rollout tracking is not available for {} {}/{}", kind, namespace, name),
+        }
+    }
+
+    #[tauri::command]
+    pub async fn track_rollout_status(
+        app_handle: tauri::AppHandle,
+        context: &str,
+        namespace: &str,
+        kind: RolloutKind,
+        name: &str,
+        tracking_id: String,
+        timeout_seconds: Option<u64>,
+    ) -> Result<(), SerializableKubeError> {
+        let client = RequestScope::new(None).client(context).await?;
+        track_rollout(
+            app_handle,
+            client,
+            namespace,
+            kind,
+            name,
+            tracking_id,
+            timeout_seconds.map(Duration::from_secs),
+        );
+        Ok(())
+    }
+
+    #[tauri::command]
+    pub async fn stop_rollout_tracking(tracking_id: &str) {
+        debug!("Stopping rollout tracking {}", tracking_id);
+        if let Some(task) = ROLLOUT_TRACKERS
+            .lock()
+            .unwrap()
+            .as_mut()
+            .and_then(|trackers| trackers.remove(tracking_id))
+        {
+            task.abort();
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn deployment(spec: Value, status: Value) -> Deployment {
+            serde_json::from_value(json!({
+                "metadata": { "name": "web", "generation": 2 },
+                "spec": spec,
+                "status": status,
+            }))
+            .unwrap()
+        }
+
+        fn response(code: u16) -> kube::core::ErrorResponse {
+            kube::core::ErrorResponse {
+                status: "Failure".to_string(),
+                message: String::new(),
+                reason: String::new(),
+                code,
+            }
+        }
+
+        #[test]
+        fn classify_watch_errors() {
+            let forbidden = watcher::Error::InitialListFailed(kube::Error::Api(response(403)));
+            assert_eq!(watch_error_kind(&forbidden), ErrorKind::Forbidden);
+            assert_eq!(watch_error_kind(&watcher::Error::WatchError(response(410))), ErrorKind::Expired);
+            assert_eq!(watch_error_kind(&watcher::Error::NoResourceVersion), ErrorKind::Unknown);
+        }
+
+        fn statefulset(spec: Value, status: Value) -> StatefulSet {
+            serde_json::from_value(json!({
+                "metadata": { "name": "db", "generation": 2 },
+                "spec": spec,
+                "status": status,
+            }))
+            .unwrap()
+        }
+
+        #[test]
+        fn deployment_waits_for_the_new_generation() {
+            let progress = deployment_progress(&deployment(
+                json!({ "replicas": 3 }),
+                json!({ "observedGeneration": 1, "replicas": 3, "updatedReplicas": 3, "availableReplicas": 3 }),
+            ));
+            assert_eq!(progress.phase, RolloutPhase::Progressing);
+            assert_eq!(progress.message, "Waiting for the rollout to be observed");
+        }
+
+        #[test]
+        fn deployment_progress_steps() {
+            let cases = [
+                (json!({ "replicas": 3, "updatedReplicas": 1, "availableReplicas": 3 }), "1 of 3 new replicas have been updated"),
+                (json!({ "replicas": 4, "updatedReplicas": 3, "availableReplicas": 3 }), "1 old replicas are pending termination"),
+                (json!({ "replicas": 3, "updatedReplicas": 3, "availableReplicas": 2 }), "2 of 3 updated replicas are available"),
+            ];
+
+            for (mut status, message) in cases {
+                status["observedGeneration"] = json!(2);
+                let progress = deployment_progress(&deployment(json!({ "replicas": 3 }), status));
+                assert_eq!(progress.phase, RolloutPhase::Progressing);
+                assert_eq!(progress.message, message);
+            }
+        }
+
+        #[test]
+        fn deployment_complete() {
+            let progress = deployment_progress(&deployment(
+                json!({ "replicas": 3 }),
+                json!({ "observedGeneration": 2, "replicas": 3, "updatedReplicas": 3, "availableReplicas": 3 }),
+            ));
+            assert_eq!(progress.phase, RolloutPhase::Complete);
+        }
+
+        #[test]
+        fn deployment_past_its_deadline_failed() {
+            let progress = deployment_progress(&deployment(
+                json!({ "replicas": 3 }),
+                json!({
+                    "observedGeneration": 2,
+                    "replicas": 3,
+                    "updatedReplicas": 1,
+                    "conditions": [{
+                        "type": "Progressing",
+                        "status": "False",
+                        "reason": "ProgressDeadlineExceeded",
+                        "message": "ReplicaSet \"web-5d4\" has timed out progressing."
+                    }]
+                }),
+            ));
+            assert_eq!(
+                progress.phase,
+                RolloutPhase::Failed {
+                    reason: "ProgressDeadlineExceeded".to_string()
+                }
+            );
+            assert_eq!(progress.message, "ReplicaSet \"web-5d4\" has timed out progressing.");
+        }
+
+        #[test]
+        fn statefulset_progress_steps() {
+            let progress = statefulset_progress(&statefulset(
+                json!({ "replicas": 3 }),
+                json!({ "observedGeneration": 2, "replicas": 3, "readyReplicas": 2 }),
+            ));
+            assert_eq!(progress.message, "2 of 3 replicas are ready");
+
+            let progress = statefulset_progress(&statefulset(
+                json!({ "replicas": 3 }),
+                json!({
+                    "observedGeneration": 2,
+                    "replicas": 3,
+                    "readyReplicas": 3,
+                    "updatedReplicas": 1,
+                    "currentRevision": "db-1",
+                    "updateRevision": "db-2"
+                }),
+            ));
+            assert_eq!(progress.phase, RolloutPhase::Progressing);
+            assert_eq!(progress.message, "1 of 3 replicas are updated");
+
+            let progress = statefulset_progress(&statefulset(
+                json!({ "replicas": 3 }),
+                json!({
+                    "observedGeneration": 2,
+                    "replicas": 3,
+                    "readyReplicas": 3,
+                    "updatedReplicas": 3,
+                    "currentRevision": "db-2",
+                    "updateRevision": "db-2"
+                }),
+            ));
+            assert_eq!(progress.phase, RolloutPhase::Complete);
+        }
+
+        #[test]
+        fn statefulset_partitioned_rollout() {
+            let spec = json!({
+                "replicas": 3,
+                "updateStrategy": { "type": "RollingUpdate", "rollingUpdate": { "partition": 2 } }
+            });
+            let status = |updated: i32| {
+                json!({ "observedGeneration": 2, "replicas": 3, "readyReplicas": 3, "updatedReplicas": updated })
+            };
+
+            let progress = statefulset_progress(&statefulset(spec.clone(), status(0)));
+            assert_eq!(progress.message, "0 of 1 partitioned replicas are updated");
+
+            let progress = statefulset_progress(&statefulset(spec, status(1)));
+            assert_eq!(progress.phase, RolloutPhase::Complete);
+        }
+
+        #[test]
+        fn statefulset_on_delete_is_not_tracked() {
+            let progress = statefulset_progress(&statefulset(
+                json!({ "replicas": 3, "updateStrategy": { "type": "OnDelete" } }),
+                json!({ "observedGeneration": 2, "replicas": 3 }),
+            ));
+            assert!(matches!(progress.phase, RolloutPhase::Failed { .. }));
+        }
+    }
+}
+
+pub mod graph {
+    use super::client::{ErrorKind, RequestOptions, RequestScope, SerializableKubeError};
+    use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+    use k8s_openapi::api::batch::v1::{CronJob, Job};
+    use k8s_openapi::api::core::v1::{
+        ConfigMap, PersistentVolume, PersistentVolumeClaim, Pod, PodSpec, Secret, Service,
+    };
+    use k8s_openapi::api::networking::v1::Ingress;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+    use k8s_openapi::NamespaceResourceScope;
+    use kube::api::{Api, ListParams};
+    use kube::{Client, Resource};
+    use serde::de::DeserializeOwned;
+    use serde::{Deserialize, Serialize};
+    use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+    use std::fmt::Debug;
+    use tracing::{debug, error, info, warn};
+
+    /// Kinds that many objects point at. Scoped graphs include them but do not walk
+    /// through them, otherwise a shared ConfigMap would pull in every workload using it.
+    const SHARED_KINDS: [&str; 4] = ["ConfigMap", "Secret", "PersistentVolume", "Ingress"];
+
+    /// Kinds that scoped graphs do not list, only the objects their pods reference by name
+    /// are looked up once the graph is scoped.
+    const REFERENCED_KINDS: [&str; 2] = ["ConfigMap", "Secret"];
+
+    #[derive(Clone, Debug, Deserialize)]
+    pub struct GraphRoot {
+        kind: String,
+        name: String,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+    pub enum EdgeKind {
+        /// The source is listed in the target's `ownerReferences`.
+        Owns,
+        /// A Service whose selector matches the target pod.
+        Selects,
+        /// An Ingress backend pointing at the target Service.
+        RoutesTo,
+        /// A pod volume backed by the target ConfigMap, Secret or claim.
+        Mounts,
+        /// A pod reading the target ConfigMap or Secret through `envFrom` or `env`.
+        References,
+        /// A claim bound to the target PersistentVolume.
+        Binds,
+    }
+
+    #[derive(Clone, Debug, Serialize)]
+    pub struct GraphNode {
+        id: String,
+        kind: String,
+        name: String,
+        namespace: Option<String>,
+        uid: Option<String>,
+        /// Referenced by another object but not found in the cluster.
+        missing: bool,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+    pub struct GraphEdge {
+        from: String,
+        to: String,
+        kind: EdgeKind,
+    }
+
+    #[derive(Clone, Debug, Default, Serialize)]
+    pub struct ResourceGraph {
+        nodes: Vec<GraphNode>,
+        edges: Vec<GraphEdge>,
+    }
+
+    fn node_id(kind: &str, namespace: Option<&str>, name: &str) -> String {
+        match namespace {
+            Some(namespace) => format!("{}/{}/{}", kind, namespace, name),
+            None => format!("{}/{}", kind, name),
+        }
+    }
+
+    #[derive(Default)]
+    struct GraphBuilder {
+        nodes: BTreeMap<String, GraphNode>,
+        edges: Vec<GraphEdge>,
+        seen_edges: HashSet<GraphEdge>,
+        uids: HashMap<String, String>,
+        owners: Vec<(String, Option<String>, Vec<OwnerReference>)>,
+    }
+
+    impl GraphBuilder {
+        fn add<K: Resource<DynamicType = ()>>(&mut self, object: &K) -> String {
+            let meta = object.meta();
+            let kind = K::kind(&()).to_string();
+            let name = meta.name.clone().unwrap_or_default();
+            let id = node_id(&kind, meta.namespace.as_deref(), &name);
+
+            if let Some(uid) = &meta.uid {
+                self.uids.insert(uid.clone(), id.clone());
+            }
+            if let Some(owners) = &meta.owner_references {
+                self.owners
+                    .push((id.clone(), meta.namespace.clone(), owners.clone()));
+            }
+
+            self.nodes.insert(
+                id.clone(),
+                GraphNode {
+                    id: id.clone(),
+                    kind,
+                    name,
+                    namespace: meta.namespace.clone(),
+                    uid: meta.uid.clone(),
+                    missing: false,
+                },
+            );
+            id
+        }
+
+        /// Returns the node for an object referenced by name, adding a `missing` node when
+        /// it was not part of the listed objects.
+        fn reference(&mut self, kind: &str, namespace: Option<&str>, name: &str) -> String {
+            let id = node_id(kind, namespace, name);
+            self.nodes.entry(id.clone()).or_insert_with(|| GraphNode {
+                id: id.clone(),
+                kind: kind.to_string(),
+                name: name.to_string(),
+                namespace: namespace.map(String::from),
+                uid: None,
+                missing: true,
+            });
+            id
+        }
+
+        fn link(&mut self, from: &str, to: &str, kind: EdgeKind) {
+            let edge = GraphEdge {
+                from: from.to_string(),
+                to: to.to_string(),
+                kind,
+            };
+            if from != to && self.seen_edges.insert(edge.clone()) {
+                self.edges.push(edge);
+            }
+        }
+
+        fn link_owners(&mut self) {
+            for (id, namespace, owners) in std::mem::take(&mut self.owners) {
+                for owner in owners {
+                    let owner_id = match self.uids.get(&owner.uid) {
+                        Some(owner_id) => owner_id.clone(),
+                        None => self.reference(&owner.kind, namespace.as_deref(), &owner.name),
+                    };
+                    self.link(&owner_id, &id, EdgeKind::Owns);
+                }
+            }
+        }
+
+        fn link_pod_spec(&mut self, pod_id: &str, namespace: &str, spec: &PodSpec) {
+            let namespace = Some(namespace);
+
+            for volume in spec.volumes.iter().flatten() {
+                if let Some(name) = volume.config_map.as_ref().and_then(|source| source.name.as_ref()) {
+                    let target = self.reference("ConfigMap", namespace, name);
+                    self.link(pod_id, &target, EdgeKind::Mounts);
+                }
+                if let Some(name) = volume.secret.as_ref().and_then(|source| source.secret_name.as_ref()) {
+                    let target = self.reference("Secret", namespace, name);
+                    self.link(pod_id, &target, EdgeKind::Mounts);
+                }
+                if let Some(claim) = &volume.persistent_volume_claim {
+                    let target = self.reference("PersistentVolumeClaim", namespace, &claim.claim_name);
+                    self.link(pod_id, &target, EdgeKind::Mounts);
+                }
+                let projections = volume
+                    .projected
+                    .iter()
+                    .flat_map(|projected| projected.sources.iter().flatten());
+                for projection in projections {
+                    if let Some(name) = projection.config_map.as_ref().and_then(|source| source.name.as_ref()) {
+                        let target = self.reference("ConfigMap", namespace, name);
+                        self.link(pod_id, &target, EdgeKind::Mounts);
+                    }
+                    if let Some(name) = projection.secret.as_ref().and_then(|source| source.name.as_ref()) {
+                        let target = self.reference("Secret", namespace, name);
+                        self.link(pod_id, &target, EdgeKind::Mounts);
+                    }
+                }
+            }
+
+            let containers = spec
+                .containers
+                .iter()
+                .chain(spec.init_containers.iter().flatten());
+            for container in containers {
+                for source in container.env_from.iter().flatten() {
+                    if let Some(name) = source.config_map_ref.as_ref().and_then(|source| source.name.as_ref()) {
+                        let target = self.reference("ConfigMap", namespace, name);
+                        self.link(pod_id, &target, EdgeKind::References);
+                    }
+                    if let Some(name) = source.secret_ref.as_ref().and_then(|source| source.name.as_ref()) {
+                        let target = self.reference("Secret", namespace, name);
+                        self.link(pod_id, &target, EdgeKind::References);
+                    }
+                }
+
+                let value_sources = container
+                    .env
+                    .iter()
+                    .flatten()
+                    .filter_map(|env| env.value_from.as_ref());
+                for source in value_sources {
+                    if let Some(name) = source.config_map_key_ref.as_ref().and_then(|key| key.name.as_ref()) {
+                        let target = self.reference("ConfigMap", namespace, name);
+                        self.link(pod_id, &target, EdgeKind::References);
+                    }
+                    if let Some(name) = source.secret_key_ref.as_ref().and_then(|key| key.name.as_ref()) {
+                        let target = self.reference("Secret", namespace, name);
+                        self.link(pod_id, &target, EdgeKind::References);
+                    }
+                }
+            }
+        }
+
+        fn build(self) -> ResourceGraph {
+            ResourceGraph {
+                nodes: self.nodes.into_values().collect(),
+                edges: self.edges,
+            }
+        }
+    }
+
+    async fn list_namespaced<K>(
+        client: &Client,
+        namespace: &str,
+        scope: &RequestScope,
+    ) -> Result<Vec<K>, SerializableKubeError>
+    where
+        K: Resource<Scope = NamespaceResourceScope, DynamicType = ()> + Clone + DeserializeOwned + Debug,
+    {
+        let api: Api<K> = Api::namespaced(client.clone(), namespace);
+        let params = ListParams::default();
+        scope
+            .read(|| api.list(&params))
+            .await
+            .map(|list| list.items)
+            .inspect_err(|err| {
+                error!("Failed to list {} in namespace {}: {}", K::plural(&()), namespace, err.message);
+            })
+    }
+
+    fn selector_matches(selector: &BTreeMap<String, String>, labels: Option<&BTreeMap<String, String>>) -> bool {
+        !selector.is_empty()
+            && labels.is_some_and(|labels| {
+                selector
+                    .iter()
+                    .all(|(key, value)| labels.get(key) == Some(value))
+            })
+    }
+
+    async fn namespace_graph(
+        client: &Client,
+        namespace: &str,
+        list_referenced: bool,
+        scope: &RequestScope,
+    ) -> Result<GraphBuilder, SerializableKubeError> {
+        let mut builder = GraphBuilder::default();
+
+        for deployment in list_namespaced::<Deployment>(client, namespace, scope).await? {
+            builder.add(&deployment);
+        }
+        for statefulset in list_namespaced::<StatefulSet>(client, namespace, scope).await? {
+            builder.add(&statefulset);
+        }
+        for daemonset in list_namespaced::<DaemonSet>(client, namespace, scope).await? {
+            builder.add(&daemonset);
+        }
+        for replicaset in list_namespaced::<ReplicaSet>(client, namespace, scope).await? {
+            builder.add(&replicaset);
+        }
+        for cronjob in list_namespaced::<CronJob>(client, namespace, scope).await? {
+            builder.add(&cronjob);
+        }
+        for job in list_namespaced::<Job>(client, namespace, scope).await? {
+            builder.add(&job);
+        }
+        if list_referenced {
+            for configmap in list_namespaced::<ConfigMap>(client, namespace, scope).await? {
+                builder.add(&configmap);
+            }
+            for secret in list_namespaced::<Secret>(client, namespace, scope).await? {
+                builder.add(&secret);
+            }
+        }
+        let claims = list_namespaced::<PersistentVolumeClaim>(client, namespace, scope).await?;
+        for claim in &claims {
+            builder.add(claim);
+        }
+
+        let pods = list_namespaced::<Pod>(client, namespace, scope).await?;
+        let mut pod_ids = Vec::with_capacity(pods.len());
+        for pod in &pods {
+            let id = builder.add(pod);
+            if let Some(spec) = &pod.spec {
+                builder.link_pod_spec(&id, namespace, spec);
+            }
+            pod_ids.push(id);
+        }
+
+        for service in list_namespaced::<Service>(client, namespace, scope).await? {
+            let id = builder.add(&service);
+            let Some(selector) = service.spec.as_ref().and_then(|spec| spec.selector.as_ref()) else {
+                continue;
+            };
+            for (pod, pod_id) in pods.iter().zip(&pod_ids) {
+                if selector_matches(selector, pod.metadata.labels.as_ref()) {
+                    builder.link(&id, pod_id, EdgeKind::Selects);
+                }
+            }
+        }
+
+        for ingress in list_namespaced::<Ingress>(client, namespace, scope).await? {
+            let id = builder.add(&ingress);
+            let Some(spec) = &ingress.spec else {
+                continue;
+            };
+            let rule_backends = spec
+                .rules
+                .iter()
+                .flatten()
+                .filter_map(|rule| rule.http.as_ref())
+                .flat_map(|http| http.paths.iter().map(|path| &path.backend));
+            let backends = spec.default_backend.iter().chain(rule_backends);
+            for service in backends.filter_map(|backend| backend.service.as_ref()) {
+                let target = builder.reference("Service", Some(namespace), &service.name);
+                builder.link(&id, &target, EdgeKind::RoutesTo);
+            }
+        }
+
+        // Volumes are cluster scoped and commonly hidden from namespace-level users, so a
+        // failed lookup only leaves the volume unresolved instead of failing the graph.
+        let volume_api: Api<PersistentVolume> = Api::all(client.clone());
+        let bound_claims = claims.iter().filter_map(|claim| {
+            let volume = claim.spec.as_ref()?.volume_name.as_ref()?;
+            let name = claim.metadata.name.as_deref().unwrap_or_default();
+            Some((node_id("PersistentVolumeClaim", Some(namespace), name), volume))
+        });
+        for (claim_id, volume_name) in bound_claims {
+            match scope.read(|| volume_api.get_opt(volume_name)).await {
+                Ok(Some(volume)) => {
+                    builder.add(&volume);
+                }
+                Ok(None) => {}
+                // A cancelled graph is not worth finishing
+                Err(err) if err.kind == ErrorKind::Cancelled => return Err(err),
+                Err(err) => warn!("Failed to get persistent volume {}: {}", volume_name, err.message),
+            }
+            let target = builder.reference("PersistentVolume", None, volume_name);
+            builder.link(&claim_id, &target, EdgeKind::Binds);
+        }
+
+        builder.link_owners();
+        Ok(builder)
+    }
+
+    async fn get_metadata<K>(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+        scope: &RequestScope,
+    ) -> Result<Option<String>, SerializableKubeError>
+    where
+        K: Resource<Scope = NamespaceResourceScope, DynamicType = ()> + Clone + DeserializeOwned + Debug,
+    {
+        let api: Api<K> = Api::namespaced(client.clone(), namespace);
+        match scope.read(|| api.get_metadata_opt(name)).await {
+            Ok(object) => Ok(object.map(|object| object.metadata.uid.unwrap_or_default())),
+            Err(err) => {
+                error!("Failed to get {} {}/{}: {}", K::kind(&()), namespace, name, err.message);
+                Err(err)
+            }
+        }
+    }
+
+    /// Looks up the referenced kinds that a scoped graph only knows by name. Only their
+    /// metadata is fetched, the graph has no use for the data of a Secret.
+    async fn resolve_references(
+        client: &Client,
+        namespace: &str,
+        graph: &mut ResourceGraph,
+        scope: &RequestScope,
+    ) -> Result<(), SerializableKubeError> {
+        let unresolved = graph
+            .nodes
+            .iter_mut()
+            .filter(|node| node.missing && REFERENCED_KINDS.contains(&node.kind.as_str()));
+        for node in unresolved {
+            let uid = match node.kind.as_str() {
+                "ConfigMap" => get_metadata::<ConfigMap>(client, namespace, &node.name, scope).await?,
+                _ => get_metadata::<Secret>(client, namespace, &node.name, scope).await?,
+            };
+            if let Some(uid) = uid {
+                node.uid = Some(uid);
+                node.missing = false;
+            }
+        }
+        Ok(())
+    }
+
+    /// Keeps the part of the graph connected to the root. Ownership is followed both ways
+    /// and shared kinds are included as leaves.
+    fn scope_to_root(graph: ResourceGraph, root_id: &str) -> ResourceGraph {
+        let kinds: HashMap<&str, &str> = graph
+            .nodes
+            .iter()
+            .map(|node| (node.id.as_str(), node.kind.as_str()))
+            .collect();
+
+        let mut reached: HashSet<&str> = HashSet::from([root_id]);
+        let mut queue = VecDeque::from([root_id]);
+        while let Some(id) = queue.pop_front() {
+            let kind = kinds.get(id).copied().unwrap_or_default();
+            if id != root_id && SHARED_KINDS.contains(&kind) {
+                continue;
+            }
+
+            for edge in &graph.edges {
+                let next = if edge.from == id {
+                    edge.to.as_str()
+                } else if edge.to == id && !(kind == "PersistentVolumeClaim" && edge.kind != EdgeKind::Owns) {
+                    // Claims can be mounted by unrelated pods, so only walk from them to their volume.
+                    edge.from.as_str()
+                } else {
+                    continue;
+                };
+                if reached.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let reached: HashSet<String> = reached.into_iter().map(String::from).collect();
+        ResourceGraph {
+            edges: graph
+                .edges
+                .into_iter()
+                .filter(|edge| reached.contains(&edge.from) && reached.contains(&edge.to))
+                .collect(),
+            nodes: graph
+                .nodes
+                .into_iter()
+                .filter(|node| reached.contains(&node.id))
+                .collect(),
+        }
+    }
+
+    #[tauri::command]
+    pub async fn get_resource_graph(
+        context: &str,
+        namespace: &str,
+        root: Option<GraphRoot>,
+        options: Option<RequestOptions>,
+    ) -> Result<ResourceGraph, SerializableKubeError> {
+        debug!("Building resource graph for namespace {} (root: {:?})", namespace, root);
+        let scope = RequestScope::new(options.as_ref());
+        let client = scope.client(context).await?;
+        // A graph rooted at a ConfigMap or Secret needs them listed to find the root
+        let list_referenced = match &root {
+            Some(root) => REFERENCED_KINDS.iter().any(|kind| kind.eq_ignore_ascii_case(&root.kind)),
+            None => true,
+        };
+        let graph = namespace_graph(&client, namespace, list_referenced, &scope).await?.build();
+
+        let graph = match root {
+            Some(root) => {
+                let root_node = graph.nodes.iter().find(|node| {
+                    !node.missing && node.kind.eq_ignore_ascii_case(&root.kind) && node.name == root.name
+                });
+                let Some(root_node) = root_node else {
+                    return Err(SerializableKubeError {
+                        code: Some(404),
+                        ..SerializableKubeError::new(
+                            ErrorKind::NotFound,
+                            "NotFound",
+                            format!("{} {}/{} not found", root.kind, namespace, root.name),
+                        )
+                    });
+                };
+                let root_id = root_node.id.clone();
+                let mut graph = scope_to_root(graph, &root_id);
+                if !list_referenced {
+                    resolve_references(&client, namespace, &mut graph, &scope).await?;
+                }
+                graph
+            }
+            None => graph,
+        };
+
+        info!(
+            "Built resource graph for namespace {} with {} nodes and {} edges",
+            namespace,
+            graph.nodes.len(),
+            graph.edges.len()
+        );
+        Ok(graph)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn graph(edges: &[(&str, &str, EdgeKind)]) -> ResourceGraph {
+            let mut builder = GraphBuilder::default();
+            for (from, to, kind) in edges {
+                let (from_kind, from_name) = from.split_once('/').unwrap();
+                let (to_kind, to_name) = to.split_once('/').unwrap();
+                let from = builder.reference(from_kind, Some("default"), from_name);
+                let to = builder.reference(to_kind, Some("default"), to_name);
+                builder.link(&from, &to, kind.clone());
+            }
+            builder.build()
+        }
+
+        fn names(graph: &ResourceGraph) -> Vec<String> {
+            let mut names: Vec<String> = graph
+                .nodes
+                .iter()
+                .map(|node| format!("{}/{}", node.kind, node.name))
+                .collect();
+            names.sort();
+            names
+        }
+
+        #[test]
+        fn scope_follows_ownership_both_ways() {
+            let graph = graph(&[
+                ("Deployment/web", "ReplicaSet/web-1", EdgeKind::Owns),
+                ("ReplicaSet/web-1", "Pod/web-1-a", EdgeKind::Owns),
+                ("Deployment/api", "ReplicaSet/api-1", EdgeKind::Owns),
+                ("ReplicaSet/api-1", "Pod/api-1-a", EdgeKind::Owns),
+            ]);
+
+            let scoped = scope_to_root(graph, "Pod/default/web-1-a");
+            assert_eq!(names(&scoped), vec!["Deployment/web", "Pod/web-1-a", "ReplicaSet/web-1"]);
+            assert_eq!(scoped.edges.len(), 2);
+        }
+
+        #[test]
+        fn scope_does_not_walk_through_shared_kinds() {
+            let graph = graph(&[
+                ("Pod/web", "ConfigMap/settings", EdgeKind::Mounts),
+                ("Pod/api", "ConfigMap/settings", EdgeKind::References),
+                ("Service/web", "Pod/web", EdgeKind::Selects),
+                ("Ingress/public", "Service/web", EdgeKind::RoutesTo),
+                ("Ingress/public", "Service/api", EdgeKind::RoutesTo),
+            ]);
+
+            let scoped = scope_to_root(graph, "Pod/default/web");
+            assert_eq!(
+                names(&scoped),
+                vec!["ConfigMap/settings", "Ingress/public", "Pod/web", "Service/web"]
+            );
+        }
+
+        #[test]
+        fn scope_walks_from_claims_only_to_their_volume() {
+            let graph = graph(&[
+                ("Pod/web", "PersistentVolumeClaim/data", EdgeKind::Mounts),
+                ("Pod/backup", "PersistentVolumeClaim/data", EdgeKind::Mounts),
+                ("PersistentVolumeClaim/data", "PersistentVolume/pv-1", EdgeKind::Binds),
+            ]);
+
+            let scoped = scope_to_root(graph, "Pod/default/web");
+            assert_eq!(
+                names(&scoped),
+                vec!["PersistentVolume/pv-1", "PersistentVolumeClaim/data", "Pod/web"]
+            );
+        }
+
+        #[test]
+        fn shared_root_includes_its_users() {
+            let graph = graph(&[
+                ("Pod/web", "Secret/tls", EdgeKind::Mounts),
+                ("Pod/api", "Secret/tls", EdgeKind::References),
+                ("Pod/api", "ConfigMap/settings", EdgeKind::References),
+            ]);
+
+            let scoped = scope_to_root(graph, "Secret/default/tls");
+            assert_eq!(
+                names(&scoped),
+                vec!["ConfigMap/settings", "Pod/api", "Pod/web", "Secret/tls"]
+            );
+        }
+    }
 }
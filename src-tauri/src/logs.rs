@@ -24,6 +24,14 @@ pub mod structured_logging {
         data: serde_json::Value,
     }
 
+    /// Pod and container a log line originates from when logs of multiple containers are
+    /// aggregated into a single session.
+    #[derive(Clone, Debug, serde::Serialize)]
+    pub struct LogSource {
+        pub pod: String,
+        pub container: String,
+    }
+
     #[derive(Clone, Debug, serde::Serialize)]
     pub enum MatchType {
         AND,
@@ -32,7 +40,7 @@ pub mod structured_logging {
 
     #[derive(Clone, Debug, serde::Serialize)]
     pub struct Facet {
-        property: String,
+        pub(crate) property: String,
         match_type: MatchType,
         values: Vec<FacetValue>,
     }
@@ -108,6 +116,7 @@ pub mod structured_logging {
     #[tauri::command]
     pub async fn end_structured_logging_session(session_id: String) {
         info!("Ending structured logging session: {}", session_id);
+        super::streaming::stop_log_streams_for_session(&session_id);
         STRUCTURED_LOGGING_SESSIONS
             .lock()
             .unwrap()
@@ -171,18 +180,35 @@ pub mod structured_logging {
     #[tauri::command]
     pub async fn add_data_to_structured_logging_session(session_id: String, data: String) {
         info!("Adding data to structured logging session: {}", session_id);
-        // split the data by newline if there's any
-        let data = data.split("\n").collect::<Vec<&str>>();
-
-        let parsed_records = data
-            .into_iter()
-            .flat_map(|d| {
+        // split the data by newline if there's any, every line is prefixed with its timestamp
+        let lines = data
+            .split("\n")
+            .map(|d| {
                 let timestamp = d.splitn(2, ' ').next().unwrap_or("");
                 let data = d.splitn(2, ' ').nth(1).unwrap_or("");
+                (timestamp.to_string(), data.to_string())
+            })
+            .collect::<Vec<(String, String)>>();
+
+        append_lines_to_structured_logging_session(session_id, lines, None);
+    }
+
+    /// Parses `(timestamp, content)` pairs into entries of the given session and counts them
+    /// into its facets. When a source is given, its pod and container are added to every
+    /// entry. Returns the number of entries that were added.
+    pub(crate) fn append_lines_to_structured_logging_session(
+        session_id: String,
+        lines: Vec<(String, String)>,
+        source: Option<&LogSource>,
+    ) -> usize {
+        let parsed_records = lines
+            .iter()
+            .flat_map(|(timestamp, data)| {
                 extract_content(data)
                     .into_iter()
                     .filter_map(|content| match content {
-                        ExtractedContent::Json(json) => {
+                        ExtractedContent::Json(mut json) => {
+                            tag_log_record(&mut json, source);
                             update_columns_for_logging_session(session_id.clone(), &json);
                             Some(StructuredLogEntry {
                                 id: Uuid::new_v4(),
@@ -192,7 +218,11 @@ pub mod structured_logging {
                             })
                         }
                         ExtractedContent::Text(text) => {
-                            let log_record = parse_log_record(session_id.clone(), &text);
+                            let mut log_record = parse_log_record(session_id.clone(), &text);
+                            if source.is_some() {
+                                tag_log_record(&mut log_record, source);
+                                update_columns_for_logging_session(session_id.clone(), &log_record);
+                            }
                             Some(StructuredLogEntry {
                                 id: Uuid::new_v4(),
                                 content: data.to_string(),
@@ -204,6 +234,8 @@ pub mod structured_logging {
             })
             .collect::<Vec<StructuredLogEntry>>();
 
+        let added = parsed_records.len();
+
         if let Some(session) = STRUCTURED_LOGGING_SESSIONS
             .lock()
             .unwrap()
@@ -211,10 +243,25 @@ pub mod structured_logging {
             .unwrap()
             .get_mut(&session_id)
         {
+            let first_new = session.entries.len();
             session.entries.extend(parsed_records);
+            for facet in session.facets.iter_mut() {
+                count_facet_values(facet, &session.entries[first_new..]);
+            }
         }
 
-        update_unique_facet_values_for_logging_session(session_id);
+        added
+    }
+
+    // Prefixed so they cannot clash with `pod` or `container` fields of the logged JSON
+    pub(crate) const SOURCE_POD_PROPERTY: &str = "_pod";
+    pub(crate) const SOURCE_CONTAINER_PROPERTY: &str = "_container";
+
+    fn tag_log_record(record: &mut serde_json::Value, source: Option<&LogSource>) {
+        if let (Some(source), Some(obj)) = (source, record.as_object_mut()) {
+            obj.insert(SOURCE_POD_PROPERTY.to_string(), json!(source.pod));
+            obj.insert(SOURCE_CONTAINER_PROPERTY.to_string(), json!(source.container));
+        }
     }
 
     fn parse_log_record(session_id: String, data: &str) -> serde_json::Value {
@@ -648,6 +695,29 @@ pub mod structured_logging {
         data
     }
 
+    // Adds the values of the given entries to the facet's totals
+    fn count_facet_values(facet: &mut Facet, entries: &[StructuredLogEntry]) {
+        let mut values: HashMap<String, u32> = HashMap::new();
+        for entry in entries.iter() {
+            if let Some(value) = entry.data.get(&facet.property) {
+                let key = serde_json::to_string(value).unwrap();
+                *values.entry(key).or_insert(0) += 1;
+            }
+        }
+
+        for (value, total) in values {
+            if let Some(facet_value) = facet.values.iter_mut().find(|v| v.value == value) {
+                facet_value.total += total;
+            } else {
+                facet.values.push(FacetValue {
+                    value,
+                    filtered: false,
+                    total,
+                });
+            }
+        }
+    }
+
     fn update_unique_facet_values_for_logging_session(session_id: String) {
         if let Some(session) = STRUCTURED_LOGGING_SESSIONS
             .lock()
@@ -683,3 +753,341 @@ pub mod structured_logging {
         }
     }
 }
+
+pub mod streaming {
+    use super::structured_logging::{
+        add_facet_to_structured_logging_session, append_lines_to_structured_logging_session,
+        get_facets_for_structured_logging_session, start_structured_logging_session, LogSource,
+        SOURCE_CONTAINER_PROPERTY, SOURCE_POD_PROPERTY,
+    };
+    use crate::kubernetes::client::{client_with_context, SerializableKubeError};
+    use futures::{AsyncBufReadExt, StreamExt};
+    use k8s_openapi::api::core::v1::Pod;
+    use kube::api::{Api, LogParams};
+    use kube::runtime::{watcher, WatchStreamExt};
+    use kube::ResourceExt;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tauri::async_runtime::JoinHandle;
+    use tauri::Emitter;
+    use tracing::{debug, info, warn};
+
+    // Upper bound of lines that are parsed and announced to the frontend in one go
+    const MAX_BATCH_SIZE: usize = 500;
+
+    static LOG_STREAMS: Mutex<Option<HashMap<String, JoinHandle<()>>>> = Mutex::new(None);
+
+    #[derive(Clone, Debug, Deserialize)]
+    pub struct LogStreamOptions {
+        pub container: Option<String>,
+        pub since_seconds: Option<i64>,
+        pub tail_lines: Option<i64>,
+        pub timestamps: Option<bool>,
+        pub previous: Option<bool>,
+    }
+
+    impl LogStreamOptions {
+        fn log_params(&self) -> LogParams {
+            let previous = self.previous.unwrap_or(false);
+            LogParams {
+                container: self.container.clone(),
+                follow: !previous,
+                previous,
+                since_seconds: self.since_seconds,
+                tail_lines: self.tail_lines,
+                timestamps: self.timestamps.unwrap_or(true),
+                ..LogParams::default()
+            }
+        }
+    }
+
+    #[derive(Clone, Serialize)]
+    #[serde(tag = "type")]
+    pub enum LogStreamEvent {
+        Batch { added: usize },
+        Ended { source: Option<LogSource> },
+        Error { message: String },
+    }
+
+    fn emit_log_stream_event(app_handle: &tauri::AppHandle, session_id: &str, event: LogStreamEvent) {
+        if let Err(err) = app_handle.emit(format!("log_stream_{}", session_id).as_ref(), event) {
+            warn!("Failed to emit log stream event for session {}: {}", session_id, err);
+        }
+    }
+
+    fn split_log_line(line: String, timestamps: bool) -> (String, String) {
+        if !timestamps {
+            return (String::new(), line);
+        }
+
+        match line.split_once(' ') {
+            Some((timestamp, content)) => (timestamp.to_string(), content.to_string()),
+            None => (line, String::new()),
+        }
+    }
+
+    async fn follow_pod_logs(
+        app_handle: tauri::AppHandle,
+        session_id: String,
+        api: Api<Pod>,
+        pod_name: String,
+        params: LogParams,
+        source: Option<LogSource>,
+    ) {
+        let reader = match api.log_stream(&pod_name, &params).await {
+            Ok(reader) => reader,
+            Err(err) => {
+                warn!("Failed to open log stream for pod {}: {}", pod_name, err);
+                emit_log_stream_event(&app_handle, &session_id, LogStreamEvent::Error { message: err.to_string() });
+                return;
+            }
+        };
+
+        let mut batches = Box::pin(reader).lines().ready_chunks(MAX_BATCH_SIZE);
+        while let Some(batch) = batches.next().await {
+            let mut lines = Vec::with_capacity(batch.len());
+            let mut failure = None;
+            for line in batch {
+                match line {
+                    Ok(line) => lines.push(split_log_line(line, params.timestamps)),
+                    Err(err) => {
+                        failure = Some(err);
+                        break;
+                    }
+                }
+            }
+
+            if !lines.is_empty() {
+                let added =
+                    append_lines_to_structured_logging_session(session_id.clone(), lines, source.as_ref());
+                emit_log_stream_event(&app_handle, &session_id, LogStreamEvent::Batch { added });
+            }
+
+            if let Some(err) = failure {
+                warn!("Log stream for pod {} failed: {}", pod_name, err);
+                emit_log_stream_event(&app_handle, &session_id, LogStreamEvent::Error { message: err.to_string() });
+                return;
+            }
+        }
+
+        debug!("Log stream for pod {} ended", pod_name);
+        emit_log_stream_event(&app_handle, &session_id, LogStreamEvent::Ended { source });
+    }
+
+    /// Followers of the individual containers matched by a label selector, aborted together
+    /// with the aggregating task that owns them.
+    #[derive(Default)]
+    struct ContainerFollowers(HashMap<(String, String), (i32, JoinHandle<()>)>);
+
+    impl Drop for ContainerFollowers {
+        fn drop(&mut self) {
+            for (_, task) in self.0.values() {
+                task.abort();
+            }
+        }
+    }
+
+    /// Follows every container of the pods matching the selector. Pods that start matching
+    /// later arrive through the pod watch, and a changed restart count starts a follower for
+    /// the container's new instance. Init containers and containers that already terminated
+    /// are followed as well, since their logs stay readable until the pod is removed.
+    async fn follow_selector_logs(
+        app_handle: tauri::AppHandle,
+        session_id: String,
+        api: Api<Pod>,
+        label_selector: String,
+        options: LogStreamOptions,
+    ) {
+        let mut followers = ContainerFollowers::default();
+        let config = watcher::Config::default().labels(&label_selector);
+        let mut pods = watcher(api.clone(), config).default_backoff().boxed();
+
+        while let Some(event) = pods.next().await {
+            match event {
+                Ok(watcher::Event::Apply(pod)) | Ok(watcher::Event::InitApply(pod)) => {
+                    let pod_name = pod.name_any();
+                    let statuses = pod
+                        .status
+                        .as_ref()
+                        .map(|status| {
+                            let init = status.init_container_statuses.iter().flatten();
+                            init.chain(status.container_statuses.iter().flatten()).cloned().collect()
+                        })
+                        .unwrap_or_else(Vec::new);
+
+                    for status in statuses {
+                        if options.container.as_ref().is_some_and(|c| *c != status.name) {
+                            continue;
+                        }
+
+                        // Waiting containers have nothing to read yet, they are picked up once
+                        // a later pod update shows them running
+                        let started = status
+                            .state
+                            .as_ref()
+                            .is_some_and(|state| state.running.is_some() || state.terminated.is_some());
+                        let key = (pod_name.clone(), status.name.clone());
+                        let known_restarts = followers.0.get(&key).map(|(restarts, _)| *restarts);
+                        if !started || known_restarts == Some(status.restart_count) {
+                            continue;
+                        }
+
+                        let mut params = options.log_params();
+                        params.container = Some(status.name.clone());
+                        if known_restarts.is_some() {
+                            // The container restarted, everything it logged so far is new to us
+                            params.since_seconds = None;
+                            params.tail_lines = None;
+                        }
+
+                        debug!("Following logs of container {} in pod {}", status.name, pod_name);
+                        let task = tauri::async_runtime::spawn(follow_pod_logs(
+                            app_handle.clone(),
+                            session_id.clone(),
+                            api.clone(),
+                            pod_name.clone(),
+                            params,
+                            Some(LogSource {
+                                pod: pod_name.clone(),
+                                container: status.name.clone(),
+                            }),
+                        ));
+
+                        if let Some((_, previous)) = followers.0.insert(key, (status.restart_count, task)) {
+                            previous.abort();
+                        }
+                    }
+                }
+                Ok(watcher::Event::Delete(pod)) => {
+                    let pod_name = pod.name_any();
+                    followers.0.retain(|(pod, _), (_, task)| {
+                        if *pod == pod_name {
+                            task.abort();
+                            return false;
+                        }
+                        true
+                    });
+                    debug!("Stopped following logs of deleted pod {}", pod_name);
+                }
+                Ok(watcher::Event::Init) | Ok(watcher::Event::InitDone) => {}
+                Err(err) => {
+                    warn!("Pod watch for selector {} failed, retrying: {}", label_selector, err);
+                    emit_log_stream_event(&app_handle, &session_id, LogStreamEvent::Error { message: err.to_string() });
+                }
+            }
+        }
+    }
+
+    fn register_log_stream(session_id: &str, task: JoinHandle<()>) {
+        if let Some(previous) = LOG_STREAMS
+            .lock()
+            .unwrap()
+            .get_or_insert_with(HashMap::new)
+            .insert(session_id.to_string(), task)
+        {
+            previous.abort();
+        }
+    }
+
+    pub(crate) fn stop_log_streams_for_session(session_id: &str) {
+        if let Some(task) = LOG_STREAMS
+            .lock()
+            .unwrap()
+            .as_mut()
+            .and_then(|streams| streams.remove(session_id))
+        {
+            task.abort();
+            info!("Stopped log stream for session {}", session_id);
+        }
+    }
+
+    #[tauri::command]
+    pub async fn start_log_stream(
+        app_handle: tauri::AppHandle,
+        context: &str,
+        namespace: &str,
+        pod_name: &str,
+        options: LogStreamOptions,
+        session_id: Option<String>,
+    ) -> Result<String, SerializableKubeError> {
+        debug!("Starting log stream for pod {}/{} in context {}", namespace, pod_name, context);
+        let client = client_with_context(context).await?;
+        let api: Api<Pod> = Api::namespaced(client, namespace);
+
+        let session_id = match session_id {
+            Some(session_id) => session_id,
+            None => start_structured_logging_session(Vec::new()).await,
+        };
+
+        let task = tauri::async_runtime::spawn(follow_pod_logs(
+            app_handle,
+            session_id.clone(),
+            api,
+            pod_name.to_string(),
+            options.log_params(),
+            None,
+        ));
+        register_log_stream(&session_id, task);
+
+        info!("Streaming logs of pod {}/{} into session {}", namespace, pod_name, session_id);
+        Ok(session_id)
+    }
+
+    #[tauri::command]
+    pub async fn start_selector_log_stream(
+        app_handle: tauri::AppHandle,
+        context: &str,
+        namespace: &str,
+        label_selector: &str,
+        options: LogStreamOptions,
+        session_id: Option<String>,
+    ) -> Result<String, SerializableKubeError> {
+        debug!(
+            "Starting log stream for selector {} in namespace {} for context {}",
+            label_selector, namespace, context
+        );
+        let client = client_with_context(context).await?;
+        let api: Api<Pod> = Api::namespaced(client, namespace);
+
+        let session_id = match session_id {
+            Some(session_id) => session_id,
+            None => start_structured_logging_session(Vec::new()).await,
+        };
+
+        let facets = get_facets_for_structured_logging_session(session_id.clone()).await;
+        for property in [SOURCE_POD_PROPERTY, SOURCE_CONTAINER_PROPERTY] {
+            if facets.iter().any(|facet| facet.property == property) {
+                continue;
+            }
+
+            add_facet_to_structured_logging_session(
+                session_id.clone(),
+                property.to_string(),
+                "OR".to_string(),
+            )
+            .await;
+        }
+
+        let task = tauri::async_runtime::spawn(follow_selector_logs(
+            app_handle,
+            session_id.clone(),
+            api,
+            label_selector.to_string(),
+            options,
+        ));
+        register_log_stream(&session_id, task);
+
+        info!(
+            "Streaming logs of pods matching {} in namespace {} into session {}",
+            label_selector, namespace, session_id
+        );
+        Ok(session_id)
+    }
+
+    #[tauri::command]
+    pub async fn stop_log_stream(session_id: String) {
+        debug!("Stopping log stream for session {}", session_id);
+        stop_log_streams_for_session(&session_id);
+    }
+}
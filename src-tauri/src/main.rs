@@ -158,8 +158,15 @@ fn main() {
             write_log,
             get_logs,
             kubernetes::client::set_current_kubeconfig,
+            kubernetes::client::invalidate_clients,
+            kubernetes::client::cancel_request,
+            kubernetes::client::set_kubeconfig_sources,
+            kubernetes::client::list_kubeconfig_files,
+            kubernetes::kubeconfig_watch::watch_kubeconfig_changes,
             kubernetes::client::list_contexts,
             kubernetes::client::get_context_auth_info,
+            kubernetes::auth::diagnose_context_auth,
+            kubernetes::probe::probe_contexts,
             kubernetes::client::get_current_context,
             kubernetes::client::list_namespaces,
             kubernetes::client::get_core_api_versions,
@@ -189,13 +196,58 @@ fn main() {
             kubernetes::client::replace_service,
             kubernetes::client::replace_ingress,
             kubernetes::client::replace_persistentvolumeclaim,
+            kubernetes::client::apply_pod,
+            kubernetes::client::apply_deployment,
+            kubernetes::client::apply_job,
+            kubernetes::client::apply_cronjob,
+            kubernetes::client::apply_configmap,
+            kubernetes::client::apply_secret,
+            kubernetes::client::apply_service,
+            kubernetes::client::apply_ingress,
+            kubernetes::client::apply_persistentvolumeclaim,
             kubernetes::client::get_pod_metrics,
             kubernetes::client::get_pod_metric,
             kubernetes::client::trigger_cronjob,
             kubernetes::client::run_kubectl,
+            kubernetes::dynamic::list_resources,
+            kubernetes::dynamic::get_resource,
+            kubernetes::dynamic::replace_resource,
+            kubernetes::dynamic::patch_resource,
+            kubernetes::dynamic::apply_resource,
+            kubernetes::dynamic::delete_resource,
+            kubernetes::diff::diff_resource,
+            kubernetes::events::list_events,
+            kubernetes::events::watch_events,
+            kubernetes::events::stop_event_watch,
+            kubernetes::nodes::list_nodes,
+            kubernetes::nodes::cordon_node,
+            kubernetes::nodes::uncordon_node,
+            kubernetes::nodes::drain_node,
+            kubernetes::scale::scale_resource,
+            kubernetes::rollout::get_rollout_history,
+            kubernetes::rollout::diff_rollout_revisions,
+            kubernetes::rollout::rollback_rollout,
+            kubernetes::rollout::pause_rollout,
+            kubernetes::rollout::resume_rollout,
+            kubernetes::rollout::track_rollout_status,
+            kubernetes::rollout::stop_rollout_tracking,
+            kubernetes::graph::get_resource_graph,
+            kubernetes::watch::subscribe_to_resource_watch,
+            kubernetes::watch::get_resource_watch_snapshot,
+            kubernetes::watch::unsubscribe_from_resource_watch,
+            kubernetes::port_forward::start_port_forward,
+            kubernetes::port_forward::list_port_forwards,
+            kubernetes::port_forward::stop_port_forward,
             shell::tty::create_tty_session,
             shell::tty::stop_tty_session,
             shell::tty::write_to_pty,
+            shell::tty::create_exec_session,
+            shell::tty::resize_pty,
+            shell::tty::start_tty_recording,
+            shell::tty::stop_tty_recording,
+            shell::recording::list_tty_recordings,
+            shell::recording::replay_tty_recording,
+            shell::recording::stop_tty_replay,
             logs::structured_logging::start_structured_logging_session,
             logs::structured_logging::repurpose_structured_logging_session,
             logs::structured_logging::end_structured_logging_session,
@@ -207,6 +259,9 @@ fn main() {
             logs::structured_logging::get_columns_for_structured_logging_session,
             logs::structured_logging::set_filtered_for_facet_value,
             logs::structured_logging::get_filtered_data_for_structured_logging_session,
+            logs::streaming::start_log_stream,
+            logs::streaming::start_selector_log_stream,
+            logs::streaming::stop_log_stream,
         ])
         .setup(|_app| {
             #[cfg(target_os = "macos")]
@@ -249,6 +304,12 @@ fn main() {
                 });
             }
 
+            // Switching sources replaces this watcher with one on the selected files
+            kubernetes::kubeconfig_watch::watch_kubeconfigs(
+                _app.handle().clone(),
+                &kubernetes::client::current_kubeconfig_source(),
+            );
+
             let _window = _app.get_webview_window("main").unwrap();
 
             #[cfg(target_os = "macos")]
@@ -1,25 +1,73 @@
 pub mod tty {
-    use portable_pty::{native_pty_system, CommandBuilder, PtySize};
+    use super::recording;
+    use crate::kubernetes::client::{client_with_context, SerializableKubeError};
+    use futures::channel::mpsc::Sender;
+    use k8s_openapi::api::core::v1::Pod;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Status;
+    use kube::api::{Api, AttachParams, TerminalSize};
+    use portable_pty::{native_pty_system, CommandBuilder, MasterPty, PtySize};
     use std::collections::HashMap;
     use std::ffi::OsString;
     use std::{
-        io::{BufRead, BufReader, Write},
+        io::{BufRead, BufReader, ErrorKind, Write},
         sync::{Arc, Mutex},
         thread::{self, sleep},
-        time::Duration,
+        time::{Duration, Instant},
     };
     use tauri::Emitter;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+    use tokio::sync::oneshot;
     use uuid::Uuid;
     use tracing::{info, warn, error};
 
     struct TerminalSession {
         writer: Arc<Mutex<Box<dyn Write + Send>>>,
+        // Local processes are resized through the master side of their PTY,
+        // exec sessions through the terminal size channel of the attached process
+        master: Option<Box<dyn MasterPty + Send>>,
+        terminal_size: Option<Sender<TerminalSize>>,
+        // Forcefully ends the process when it does not exit by itself
+        terminate: Option<Box<dyn FnOnce() + Send>>,
+        rows: u16,
+        cols: u16,
+    }
+
+    #[derive(Clone, serde::Serialize)]
+    struct TtyExit {
+        exit_code: Option<i32>,
+    }
+
+    // Time a session gets to exit after being asked to, before it is killed
+    const STOP_TIMEOUT: Duration = Duration::from_secs(3);
+
+    /// Hands data written by `write_to_pty` over to the task feeding the stdin of an exec session.
+    struct ExecStdinWriter {
+        sender: UnboundedSender<Vec<u8>>,
+    }
+
+    impl Write for ExecStdinWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.sender
+                .send(buf.to_vec())
+                .map_err(|_| std::io::Error::from(std::io::ErrorKind::BrokenPipe))?;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
     }
 
     static TTY_SESSIONS: Mutex<Option<HashMap<String, TerminalSession>>> = Mutex::new(None);
 
     #[tauri::command]
-    pub fn create_tty_session(app_handle: tauri::AppHandle, init_command: Vec<String>) -> String {
+    pub fn create_tty_session(
+        app_handle: tauri::AppHandle,
+        init_command: Vec<String>,
+        rows: Option<u16>,
+        cols: Option<u16>,
+    ) -> String {
         info!("Creating TTY session");
         if TTY_SESSIONS.lock().unwrap().is_none() {
             *TTY_SESSIONS.lock().unwrap() = Some(HashMap::new());
@@ -28,8 +76,8 @@ pub mod tty {
         let pty_system = native_pty_system();
         let pty_pair = pty_system
             .openpty(PtySize {
-                rows: 24,
-                cols: 80,
+                rows: rows.unwrap_or(24),
+                cols: cols.unwrap_or(80),
                 pixel_width: 0,
                 pixel_height: 0,
             })
@@ -50,13 +98,40 @@ pub mod tty {
         );
 
         let mut child = pty_pair.slave.spawn_command(cmd).unwrap();
-        thread::spawn(move || {
-            child.wait().unwrap();
-        });
+        let mut killer = child.clone_killer();
 
         let reader = pty_pair.master.try_clone_reader().unwrap();
         let reader = Arc::new(Mutex::new(Some(BufReader::new(reader))));
 
+        // register the session before any thread can observe the process exiting
+        let writer = pty_pair.master.take_writer().unwrap();
+        TTY_SESSIONS.lock().unwrap().as_mut().unwrap().insert(
+            session_id.clone(),
+            TerminalSession {
+                writer: Arc::new(Mutex::new(writer)),
+                master: Some(pty_pair.master),
+                terminal_size: None,
+                terminate: Some(Box::new(move || {
+                    let _ = killer.kill();
+                })),
+                rows: rows.unwrap_or(24),
+                cols: cols.unwrap_or(80),
+            },
+        );
+
+        let exit_app_handle = app_handle.clone();
+        let exit_session_id = session_id.clone();
+        thread::spawn(move || {
+            let exit_code = match child.wait() {
+                Ok(status) => Some(status.exit_code() as i32),
+                Err(err) => {
+                    warn!("Failed to wait for TTY session {}: {}", exit_session_id, err);
+                    None
+                }
+            };
+            end_tty_session(&exit_app_handle, &exit_session_id, exit_code);
+        });
+
         thread::spawn(move || {
             let reader = reader.lock().unwrap().take();
             let app = app_handle.clone();
@@ -64,37 +139,276 @@ pub mod tty {
             if let Some(mut reader) = reader {
                 loop {
                     sleep(Duration::from_millis(1));
-                    let data = reader.fill_buf().unwrap().to_vec();
+                    let data = match reader.fill_buf() {
+                        Ok([]) => break,
+                        Ok(data) => data.to_vec(),
+                        Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                        Err(err) => {
+                            // reading the master side fails with EIO once the process is gone
+                            info!("TTY session {} reader stopped: {}", session_id, err);
+                            break;
+                        }
+                    };
                     reader.consume(data.len());
-                    if data.len() > 0 {
-                        app.emit(format!("tty_data_{}", session_id).as_ref(), data)
-                            .unwrap();
+                    recording::record_output(&session_id, &data);
+                    if let Err(err) = app.emit(format!("tty_data_{}", session_id).as_ref(), data) {
+                        // the window that listened to this session is gone
+                        error!("Failed to emit output of TTY session {}: {}", session_id, err);
+                        break;
                     }
                 }
             }
         });
 
-        let writer = pty_pair.master.take_writer().unwrap();
+        return session_id;
+    }
+
+    /// Removes a session whose process has exited and lets the frontend know about its exit code.
+    fn end_tty_session(app_handle: &tauri::AppHandle, session_id: &str, exit_code: Option<i32>) {
+        let removed = TTY_SESSIONS
+            .lock()
+            .unwrap()
+            .as_mut()
+            .and_then(|sessions| sessions.remove(session_id));
+
+        if removed.is_some() {
+            info!("TTY session {} exited with code {:?}", session_id, exit_code);
+            recording::stop(session_id);
+            if let Err(err) = app_handle.emit(format!("tty_exit_{}", session_id).as_ref(), TtyExit { exit_code }) {
+                error!("Failed to emit exit of TTY session {}: {}", session_id, err);
+            }
+        }
+    }
+
+    fn exit_code_from_status(status: Option<Status>) -> Option<i32> {
+        let status = status?;
+        if status.status.as_deref() == Some("Success") {
+            return Some(0);
+        }
+
+        status
+            .details?
+            .causes?
+            .into_iter()
+            .find(|cause| cause.reason.as_deref() == Some("ExitCode"))
+            .and_then(|cause| cause.message?.parse().ok())
+    }
+
+    #[tauri::command]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_exec_session(
+        app_handle: tauri::AppHandle,
+        context: &str,
+        namespace: &str,
+        pod_name: &str,
+        container: Option<String>,
+        command: Vec<String>,
+        rows: Option<u16>,
+        cols: Option<u16>,
+    ) -> Result<String, SerializableKubeError> {
+        info!("Creating exec session for pod {}/{}", namespace, pod_name);
+        let client = client_with_context(context).await?;
+        let pod_api: Api<Pod> = Api::namespaced(client, namespace);
+
+        let mut params = AttachParams::interactive_tty();
+        if let Some(container) = container {
+            params = params.container(container);
+        }
+
+        let mut attached = pod_api.exec(pod_name, command, &params).await.map_err(|err| {
+            error!("Failed to exec into pod {}/{}: {}", namespace, pod_name, err);
+            SerializableKubeError::from(err)
+        })?;
+
+        let session_id = Uuid::new_v4().to_string();
+        let mut stdout = attached.stdout().unwrap();
+        let mut stdin = attached.stdin().unwrap();
+        let mut terminal_size = attached.terminal_size();
+
+        if let Some(sender) = terminal_size.as_mut() {
+            let _ = sender.try_send(TerminalSize {
+                width: cols.unwrap_or(80),
+                height: rows.unwrap_or(24),
+            });
+        }
+
+        let exit_app_handle = app_handle.clone();
+        let thread_session_id = session_id.clone();
+        tauri::async_runtime::spawn(async move {
+            let mut buffer = vec![0u8; 8192];
+            loop {
+                match stdout.read(&mut buffer).await {
+                    Ok(0) => break,
+                    Ok(read) => {
+                        recording::record_output(&thread_session_id, &buffer[..read]);
+                        let event = format!("tty_data_{}", thread_session_id);
+                        if let Err(err) = app_handle.emit(event.as_ref(), buffer[..read].to_vec()) {
+                            // the window that listened to this session is gone
+                            error!("Failed to emit output of exec session {}: {}", thread_session_id, err);
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        warn!("Failed to read from exec session {}: {}", thread_session_id, err);
+                        break;
+                    }
+                }
+            }
+        });
+
+        let (sender, mut receiver) = unbounded_channel::<Vec<u8>>();
+        tauri::async_runtime::spawn(async move {
+            while let Some(data) = receiver.recv().await {
+                if stdin.write_all(&data).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        if TTY_SESSIONS.lock().unwrap().is_none() {
+            *TTY_SESSIONS.lock().unwrap() = Some(HashMap::new());
+        }
+
+        let (kill_sender, kill_receiver) = oneshot::channel::<()>();
         TTY_SESSIONS.lock().unwrap().as_mut().unwrap().insert(
             session_id.clone(),
             TerminalSession {
-                writer: Arc::new(Mutex::new(writer)),
+                writer: Arc::new(Mutex::new(Box::new(ExecStdinWriter { sender }))),
+                master: None,
+                terminal_size,
+                terminate: Some(Box::new(move || {
+                    let _ = kill_sender.send(());
+                })),
+                rows: rows.unwrap_or(24),
+                cols: cols.unwrap_or(80),
             },
         );
 
-        return session_id;
+        let thread_session_id = session_id.clone();
+        let status = attached.take_status();
+        tauri::async_runtime::spawn(async move {
+            let exit_code = tokio::select! {
+                status = async {
+                    match status {
+                        Some(status) => status.await,
+                        None => None,
+                    }
+                } => exit_code_from_status(status),
+                _ = kill_receiver => {
+                    attached.abort();
+                    None
+                }
+            };
+
+            if let Err(err) = attached.join().await {
+                warn!("Exec session {} ended with an error: {}", thread_session_id, err);
+            }
+            end_tty_session(&exit_app_handle, &thread_session_id, exit_code);
+        });
+
+        Ok(session_id)
+    }
+
+    #[tauri::command]
+    pub fn resize_pty(session_id: &str, rows: u16, cols: u16) {
+        info!("Resizing TTY session {} to {}x{}", session_id, rows, cols);
+        let mut sessions_lock = TTY_SESSIONS.lock().unwrap();
+
+        if let Some(session) = sessions_lock.as_mut().and_then(|sessions| sessions.get_mut(session_id)) {
+            session.rows = rows;
+            session.cols = cols;
+            recording::record_resize(session_id, rows, cols);
+
+            if let Some(master) = session.master.as_ref() {
+                if let Err(err) = master.resize(PtySize {
+                    rows,
+                    cols,
+                    pixel_width: 0,
+                    pixel_height: 0,
+                }) {
+                    warn!("Failed to resize TTY session {}: {}", session_id, err);
+                }
+            }
+
+            if let Some(sender) = session.terminal_size.as_mut() {
+                if let Err(err) = sender.try_send(TerminalSize {
+                    width: cols,
+                    height: rows,
+                }) {
+                    warn!("Failed to resize exec session {}: {}", session_id, err);
+                }
+            }
+        }
     }
 
     #[tauri::command]
-    pub fn stop_tty_session(session_id: &str) {
+    pub fn stop_tty_session(app_handle: tauri::AppHandle, session_id: &str) {
         info!("Stopping TTY session: {}", session_id);
         // write to pty to kill the process, this can be a bash or powershell command
         write_to_pty(session_id, "exit\n");
+
+        // give the process some time to exit by itself before killing it
+        let session_id = session_id.to_string();
+        thread::spawn(move || {
+            let deadline = Instant::now() + STOP_TIMEOUT;
+            while Instant::now() < deadline {
+                let running = TTY_SESSIONS
+                    .lock()
+                    .unwrap()
+                    .as_ref()
+                    .is_some_and(|sessions| sessions.contains_key(&session_id));
+                if !running {
+                    return;
+                }
+                sleep(Duration::from_millis(100));
+            }
+
+            let terminate = TTY_SESSIONS
+                .lock()
+                .unwrap()
+                .as_mut()
+                .and_then(|sessions| sessions.get_mut(&session_id))
+                .and_then(|session| session.terminate.take());
+
+            if let Some(terminate) = terminate {
+                warn!("TTY session {} did not exit in time, killing it", session_id);
+                terminate();
+            }
+
+            // the exit is normally reported by the session itself, make sure it is gone regardless
+            sleep(Duration::from_millis(500));
+            end_tty_session(&app_handle, &session_id, None);
+        });
+    }
+
+    #[tauri::command]
+    pub fn start_tty_recording(
+        app_handle: tauri::AppHandle,
+        session_id: &str,
+        include_input: bool,
+    ) -> Result<String, String> {
+        info!("Starting recording of TTY session: {}", session_id);
+        let (rows, cols) = TTY_SESSIONS
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|sessions| sessions.get(session_id))
+            .map(|session| (session.rows, session.cols))
+            .ok_or_else(|| format!("TTY session {} not found", session_id))?;
+
+        recording::start(&app_handle, session_id, rows, cols, include_input)
+    }
+
+    #[tauri::command]
+    pub fn stop_tty_recording(session_id: &str) {
+        info!("Stopping recording of TTY session: {}", session_id);
+        recording::stop(session_id);
     }
 
     #[tauri::command]
     pub fn write_to_pty(session_id: &str, data: &str) {
         info!("Writing to TTY session: {}", session_id);
+        recording::record_input(session_id, data);
         // First, lock the sessions map
         let sessions_lock = TTY_SESSIONS.lock().unwrap();
 
@@ -115,3 +429,271 @@ pub mod tty {
         }
     }
 }
+
+pub mod recording {
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+    use std::fs::{self, File};
+    use std::io::{BufRead, BufReader, BufWriter, Write};
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread::{self, sleep};
+    use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+    use tauri::{Emitter, Manager};
+    use tracing::{error, info, warn};
+    use uuid::Uuid;
+
+    // Recordings are written in the asciicast v2 format, see
+    // https://docs.asciinema.org/manual/asciicast/v2/
+    const RECORDING_EXTENSION: &str = "cast";
+
+    struct Recording {
+        writer: BufWriter<File>,
+        started: Instant,
+        include_input: bool,
+        // trailing bytes of a UTF-8 sequence that was split across reads
+        pending_output: Vec<u8>,
+    }
+
+    impl Recording {
+        fn write_event(&mut self, code: &str, data: &str) {
+            let event = json!([self.started.elapsed().as_secs_f64(), code, data]);
+            if let Err(err) = writeln!(self.writer, "{}", event) {
+                warn!("Failed to write recording event: {}", err);
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, serde::Serialize)]
+    pub struct RecordingInfo {
+        name: String,
+        width: u64,
+        height: u64,
+        timestamp: Option<u64>,
+        size: u64,
+    }
+
+    static RECORDINGS: Mutex<Option<HashMap<String, Recording>>> = Mutex::new(None);
+    static REPLAYS: Mutex<Option<HashMap<String, Arc<AtomicBool>>>> = Mutex::new(None);
+
+    fn recordings_dir(app_handle: &tauri::AppHandle) -> Result<PathBuf, String> {
+        let dir = app_handle
+            .path()
+            .app_data_dir()
+            .map_err(|e| e.to_string())?
+            .join("recordings");
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        Ok(dir)
+    }
+
+    fn with_recording(session_id: &str, f: impl FnOnce(&mut Recording)) {
+        if let Some(recording) = RECORDINGS
+            .lock()
+            .unwrap()
+            .as_mut()
+            .and_then(|recordings| recordings.get_mut(session_id))
+        {
+            f(recording);
+        }
+    }
+
+    pub(crate) fn start(
+        app_handle: &tauri::AppHandle,
+        session_id: &str,
+        rows: u16,
+        cols: u16,
+        include_input: bool,
+    ) -> Result<String, String> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let name = format!("{}-{}.{}", timestamp, session_id, RECORDING_EXTENSION);
+        let path = recordings_dir(app_handle)?.join(&name);
+
+        let mut writer = BufWriter::new(File::create(&path).map_err(|e| e.to_string())?);
+        let header = json!({
+            "version": 2,
+            "width": cols,
+            "height": rows,
+            "timestamp": timestamp,
+        });
+        writeln!(writer, "{}", header).map_err(|e| e.to_string())?;
+
+        RECORDINGS.lock().unwrap().get_or_insert_with(HashMap::new).insert(
+            session_id.to_string(),
+            Recording {
+                writer,
+                started: Instant::now(),
+                include_input,
+                pending_output: Vec::new(),
+            },
+        );
+
+        info!("Recording TTY session {} to {}", session_id, path.display());
+        Ok(name)
+    }
+
+    pub(crate) fn stop(session_id: &str) {
+        let recording = RECORDINGS
+            .lock()
+            .unwrap()
+            .as_mut()
+            .and_then(|recordings| recordings.remove(session_id));
+
+        if let Some(mut recording) = recording {
+            if let Err(err) = recording.writer.flush() {
+                error!("Failed to finish recording of TTY session {}: {}", session_id, err);
+            }
+        }
+    }
+
+    pub(crate) fn record_output(session_id: &str, data: &[u8]) {
+        with_recording(session_id, |recording| {
+            recording.pending_output.extend_from_slice(data);
+            let complete = match std::str::from_utf8(&recording.pending_output) {
+                Ok(_) => recording.pending_output.len(),
+                Err(err) if err.error_len().is_none() => err.valid_up_to(),
+                Err(_) => recording.pending_output.len(),
+            };
+
+            let output: Vec<u8> = recording.pending_output.drain(..complete).collect();
+            if !output.is_empty() {
+                recording.write_event("o", &String::from_utf8_lossy(&output));
+            }
+        });
+    }
+
+    pub(crate) fn record_input(session_id: &str, data: &str) {
+        with_recording(session_id, |recording| {
+            if recording.include_input {
+                recording.write_event("i", data);
+            }
+        });
+    }
+
+    pub(crate) fn record_resize(session_id: &str, rows: u16, cols: u16) {
+        with_recording(session_id, |recording| {
+            recording.write_event("r", &format!("{}x{}", cols, rows));
+        });
+    }
+
+    #[tauri::command]
+    pub fn list_tty_recordings(app_handle: tauri::AppHandle) -> Result<Vec<RecordingInfo>, String> {
+        let dir = recordings_dir(&app_handle)?;
+        let mut recordings = Vec::new();
+
+        for entry in fs::read_dir(dir).map_err(|e| e.to_string())?.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(RECORDING_EXTENSION) {
+                continue;
+            }
+
+            let header = File::open(&path)
+                .ok()
+                .and_then(|file| BufReader::new(file).lines().next()?.ok())
+                .and_then(|line| serde_json::from_str::<Value>(&line).ok())
+                .unwrap_or(Value::Null);
+
+            recordings.push(RecordingInfo {
+                name: entry.file_name().to_string_lossy().to_string(),
+                width: header["width"].as_u64().unwrap_or(80),
+                height: header["height"].as_u64().unwrap_or(24),
+                timestamp: header["timestamp"].as_u64(),
+                size: entry.metadata().map(|m| m.len()).unwrap_or_default(),
+            });
+        }
+
+        recordings.sort_by_key(|recording| std::cmp::Reverse(recording.timestamp));
+        Ok(recordings)
+    }
+
+    #[tauri::command]
+    pub fn replay_tty_recording(
+        app_handle: tauri::AppHandle,
+        name: &str,
+        speed: Option<f64>,
+    ) -> Result<String, String> {
+        info!("Replaying TTY recording: {}", name);
+        // only allow replaying files from the recordings directory
+        if name.contains(['/', '\\']) || name.starts_with('.') {
+            return Err(format!("Invalid recording name: {}", name));
+        }
+
+        let file = File::open(recordings_dir(&app_handle)?.join(name)).map_err(|e| e.to_string())?;
+        let speed = speed.filter(|speed| *speed > 0.0).unwrap_or(1.0);
+
+        let replay_id = Uuid::new_v4().to_string();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        REPLAYS
+            .lock()
+            .unwrap()
+            .get_or_insert_with(HashMap::new)
+            .insert(replay_id.clone(), cancelled.clone());
+
+        let thread_replay_id = replay_id.clone();
+        thread::spawn(move || {
+            let started = Instant::now();
+            // the first line holds the header, every other line is an event
+            for line in BufReader::new(file).lines().skip(1).flatten() {
+                if cancelled.load(Ordering::Relaxed) {
+                    break;
+                }
+
+                let Ok(Value::Array(event)) = serde_json::from_str::<Value>(&line) else {
+                    continue;
+                };
+                let (Some(time), Some("o"), Some(data)) =
+                    (event[0].as_f64(), event[1].as_str(), event[2].as_str())
+                else {
+                    continue;
+                };
+
+                let due = Duration::from_secs_f64(time / speed);
+                while let Some(remaining) = due.checked_sub(started.elapsed()) {
+                    if cancelled.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    sleep(remaining.min(Duration::from_millis(100)));
+                }
+
+                if cancelled.load(Ordering::Relaxed) {
+                    break;
+                }
+
+                let event = format!("tty_data_{}", thread_replay_id);
+                if let Err(err) = app_handle.emit(event.as_ref(), data.as_bytes().to_vec()) {
+                    // the window that listened to this replay is gone
+                    error!("Failed to emit output of replay {}: {}", thread_replay_id, err);
+                    break;
+                }
+            }
+
+            REPLAYS
+                .lock()
+                .unwrap()
+                .as_mut()
+                .and_then(|replays| replays.remove(&thread_replay_id));
+            let _ = app_handle.emit(
+                format!("tty_exit_{}", thread_replay_id).as_ref(),
+                json!({ "exit_code": 0 }),
+            );
+        });
+
+        Ok(replay_id)
+    }
+
+    #[tauri::command]
+    pub fn stop_tty_replay(replay_id: &str) {
+        info!("Stopping TTY replay: {}", replay_id);
+        if let Some(cancelled) = REPLAYS
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|replays| replays.get(replay_id))
+        {
+            cancelled.store(true, Ordering::Relaxed);
+        }
+    }
+}
@@ -1,5 +1,12 @@
 import { Kubernetes } from "@/services/Kubernetes";
-import { provide, reactive, InjectionKey, toRefs, ToRefs } from "vue";
+import {
+  provide,
+  reactive,
+  InjectionKey,
+  toRefs,
+  ToRefs,
+  onUnmounted,
+} from "vue";
 import { SettingsContextStateKey } from "@/providers/SettingsContextProvider";
 import { injectStrict } from "@/lib/utils";
 
@@ -51,6 +58,22 @@ export default {
     provide(KubeContextSetContextKey, setContext);
     provide(KubeContextSetNamespaceKey, setNamespace);
 
+    // Fall back to the current context of the kubeconfig when the selected one is removed,
+    // and let a modified context try to authenticate again with its new credentials
+    const unlistenKubeconfigChanged = Kubernetes.onKubeconfigChanged(
+      (change) => {
+        if (change.removed.includes(state.context)) {
+          Kubernetes.getCurrentContext().then((context) => {
+            setContext({ context, kubeConfig: state.kubeConfig });
+            setNamespace("");
+          });
+        } else if (change.modified.includes(state.context)) {
+          state.authenticated = true;
+        }
+      }
+    );
+    onUnmounted(() => unlistenKubeconfigChanged.then((unlisten) => unlisten()));
+
     if (state.context.length === 0) {
       Kubernetes.getCurrentContext().then((context) => {
         setContext({
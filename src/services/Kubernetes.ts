@@ -1,4 +1,5 @@
 import {
+  CoreV1Event,
   KubernetesObject,
   PodMetric,
   V1APIGroup,
@@ -16,15 +17,96 @@ import {
 } from "@kubernetes/client-node";
 import { VirtualService } from "@kubernetes-models/istio/networking.istio.io/v1beta1";
 import { invoke } from "@tauri-apps/api/core";
+import { listen, UnlistenFn } from "@tauri-apps/api/event";
 import { Command } from "@tauri-apps/plugin-shell";
 
+export type KubernetesErrorKind =
+  | "Network"
+  | "Tls"
+  | "Auth"
+  | "Forbidden"
+  | "NotFound"
+  | "Expired"
+  | "Conflict"
+  | "Invalid"
+  | "TooManyRequests"
+  | "Timeout"
+  | "Cancelled"
+  | "ServerError"
+  | "MetricsUnavailable"
+  | "Kubeconfig"
+  | "ExecPlugin"
+  | "Unsupported"
+  | "Unknown";
+
 export interface KubernetesError {
   message: string;
   code: number;
   reason: string;
   details: any;
+  kind: KubernetesErrorKind;
+  causes: string[];
+}
+
+export interface RequestOptions {
+  request_id?: string;
+  timeout_seconds?: number;
+  retries?: number;
+}
+
+export interface PageOptions {
+  limit?: number;
+  continue_token?: string;
+  columns?: string[];
+}
+
+export interface ListPage<T> {
+  items: T[];
+  continue_token: string | null;
+  remaining_item_count: number | null;
+  resource_version: string | null;
+}
+
+export interface KubeconfigChange {
+  added: string[];
+  removed: string[];
+  modified: string[];
 }
 
+export interface EventTimelineEntry {
+  id: string;
+  event: CoreV1Event;
+}
+
+export type EventWatchEvent =
+  | { type: "Timeline"; entries: EventTimelineEntry[] }
+  | { type: "Upsert"; entry: EventTimelineEntry }
+  | { type: "Remove"; id: string }
+  | { type: "Error"; message: string };
+
+// Applies an update of an event watch to the timeline, most recently seen first
+export const mergeEventTimeline = (
+  timeline: EventTimelineEntry[],
+  update: EventWatchEvent
+): EventTimelineEntry[] => {
+  switch (update.type) {
+    case "Timeline":
+      return update.entries;
+    case "Remove":
+      return timeline.filter((entry) => entry.id !== update.id);
+    case "Upsert": {
+      const lastSeen = (entry: EventTimelineEntry) =>
+        new Date(entry.event.lastTimestamp ?? 0).getTime();
+      return [
+        ...timeline.filter((entry) => entry.id !== update.entry.id),
+        update.entry,
+      ].sort((a, b) => lastSeen(b) - lastSeen(a));
+    }
+    default:
+      return timeline;
+  }
+};
+
 export class Kubernetes {
   static async getAuthErrorHandler(
     context: string,
@@ -79,6 +161,32 @@ export class Kubernetes {
     };
   }
 
+  static async diagnoseAuth(
+    context: string,
+    kubeConfig?: string
+  ): Promise<{
+    context: string;
+    user: string | null;
+    method: { type: string; [key: string]: any };
+    state:
+      | "Valid"
+      | "Expired"
+      | "LoginRequired"
+      | "MissingBinary"
+      | "Failed"
+      | "Timeout";
+    reason: string | null;
+    message: string | null;
+    hint: string | null;
+    expires_at: string | null;
+    stderr: string | null;
+  }> {
+    return invoke("diagnose_context_auth", {
+      context: context,
+      kubeConfig: kubeConfig,
+    });
+  }
+
   static async getCurrentContext(): Promise<string> {
     return invoke("get_current_context", {});
   }
@@ -87,51 +195,113 @@ export class Kubernetes {
     return invoke("set_current_kubeconfig", { kubeConfig: kubeConfig });
   }
 
+  static async setKubeConfigSources(
+    source:
+      | { mode: "Default" }
+      | { mode: "Paths"; paths: string[] }
+      | { mode: "Directory"; path: string }
+  ): Promise<void> {
+    return invoke("set_kubeconfig_sources", { source: source });
+  }
+
+  static async cancelRequest(requestId: string): Promise<boolean> {
+    return invoke("cancel_request", { requestId: requestId });
+  }
+
+  static async onKubeconfigChanged(
+    handler: (change: KubeconfigChange) => void
+  ): Promise<UnlistenFn> {
+    return listen<KubeconfigChange>("kubeconfig_changed", (event) =>
+      handler(event.payload)
+    );
+  }
+
   static async getContexts(): Promise<
-    { name: string; context: { namespace: string } }[]
+    { name: string; context: { namespace: string }; source: string }[]
   > {
     return invoke("list_contexts");
   }
 
+  static async probeContexts(timeoutSeconds?: number): Promise<
+    {
+      context: string;
+      reachable: boolean;
+      ready: boolean | null;
+      server_version: string | null;
+      latency_ms: number | null;
+      auth: "Authenticated" | "Unauthenticated" | "Unknown";
+      auth_reason: string | null;
+      auth_hint: string | null;
+      tls_error: string | null;
+      error: string | null;
+    }[]
+  > {
+    return invoke("probe_contexts", { timeoutSeconds: timeoutSeconds });
+  }
+
   static async getNamespaces(
     context: string,
-    kubeConfig: string
+    kubeConfig: string,
+    options?: RequestOptions
   ): Promise<V1Namespace[]> {
     return invoke("list_namespaces", {
       context: context,
       kubeConfig: kubeConfig,
+      options: options,
     });
   }
 
+  static async listPage<T = KubernetesObject>(
+    command: string,
+    args: Record<string, unknown>,
+    page: PageOptions,
+    options?: RequestOptions
+  ): Promise<ListPage<T>> {
+    return invoke(command, { ...args, page: page, options: options });
+  }
+
   static async kubectl(args: string[]): Promise<string> {
     return invoke("run_kubectl", { args: args });
   }
 
-  static async getCoreApiVersions(context: string): Promise<string[]> {
-    return invoke("get_core_api_versions", { context: context });
+  static async getCoreApiVersions(
+    context: string,
+    options?: RequestOptions
+  ): Promise<string[]> {
+    return invoke("get_core_api_versions", {
+      context: context,
+      options: options,
+    });
   }
 
   static async getCoreApiResources(
     context: string,
-    core_api_version: string
+    core_api_version: string,
+    options?: RequestOptions
   ): Promise<V1APIResource[]> {
     return invoke("get_core_api_resources", {
       context: context,
       coreApiVersion: core_api_version,
+      options: options,
     });
   }
 
-  static async getApiGroups(context: string): Promise<V1APIGroup[]> {
-    return invoke("get_api_groups", { context: context });
+  static async getApiGroups(
+    context: string,
+    options?: RequestOptions
+  ): Promise<V1APIGroup[]> {
+    return invoke("get_api_groups", { context: context, options: options });
   }
 
   static async getApiGroupResources(
     context: string,
-    api_group_version: string
+    api_group_version: string,
+    options?: RequestOptions
   ): Promise<V1APIResource[]> {
     return invoke("get_api_group_resources", {
       context: context,
       apiGroupVersion: api_group_version,
+      options: options,
     });
   }
 
@@ -140,13 +310,15 @@ export class Kubernetes {
     namespace: string,
     type: string,
     name: string,
-    object: unknown
+    object: unknown,
+    options?: RequestOptions
   ): Promise<KubernetesObject> {
     return invoke(`replace_${type.toLowerCase()}`, {
       context: context,
       namespace: namespace,
       name: name,
       object,
+      options: options,
     }) as Promise<KubernetesObject>;
   }
 
@@ -154,94 +326,138 @@ export class Kubernetes {
     context: string,
     namespace: string,
     name: string,
-    gracePeriodSeconds = 0
+    gracePeriodSeconds = 0,
+    options?: RequestOptions
   ): Promise<void> {
     return invoke("delete_pod", {
       context: context,
       namespace: namespace,
       name: name,
       gracePeriodSeconds: gracePeriodSeconds,
+      options: options,
     });
   }
 
   static async getDeployments(
     context: string,
-    namespace: string
+    namespace: string,
+    options?: RequestOptions
   ): Promise<V1Deployment[]> {
     return invoke("list_deployments", {
       context: context,
       namespace: namespace,
+      options: options,
     });
   }
 
   static async restartDeployment(
     context: string,
     namespace: string,
-    name: string
+    name: string,
+    rolloutTrackingId?: string,
+    options?: RequestOptions
   ): Promise<boolean> {
     return invoke("restart_deployment", {
       context: context,
       namespace: namespace,
       name: name,
+      rolloutTrackingId: rolloutTrackingId,
+      options: options,
     });
   }
 
   static async restartStatefulset(
     context: string,
     namespace: string,
-    name: string
+    name: string,
+    rolloutTrackingId?: string,
+    options?: RequestOptions
   ): Promise<boolean> {
     return invoke("restart_statefulset", {
       context: context,
       namespace: namespace,
       name: name,
+      rolloutTrackingId: rolloutTrackingId,
+      options: options,
     });
   }
 
-  static async getJobs(context: string, namespace: string): Promise<V1Job[]> {
+  static async getEvents(
+    context: string,
+    namespace: string,
+    filter: {
+      involved_object_kind?: string;
+      involved_object_name?: string;
+      involved_object_uid?: string;
+      event_type?: string;
+    } = {},
+    options?: RequestOptions
+  ): Promise<CoreV1Event[]> {
+    return invoke("list_events", {
+      context: context,
+      namespace: namespace,
+      filter: filter,
+      options: options,
+    });
+  }
+
+  static async getJobs(
+    context: string,
+    namespace: string,
+    options?: RequestOptions
+  ): Promise<V1Job[]> {
     return invoke("list_jobs", {
       context: context,
       namespace: namespace,
+      options: options,
     });
   }
 
   static async getCronJobs(
     context: string,
-    namespace: string
+    namespace: string,
+    options?: RequestOptions
   ): Promise<V1CronJob[]> {
     return invoke("list_cronjobs", {
       context: context,
       namespace: namespace,
+      options: options,
     });
   }
 
   static async getConfigMaps(
     context: string,
-    namespace: string
+    namespace: string,
+    options?: RequestOptions
   ): Promise<V1ConfigMap[]> {
     return invoke("list_configmaps", {
       context: context,
       namespace: namespace,
+      options: options,
     });
   }
 
   static async getSecrets(
     context: string,
-    namespace: string
+    namespace: string,
+    options?: RequestOptions
   ): Promise<V1Secret[]> {
     return invoke("list_secrets", {
       context: context,
       namespace: namespace,
+      options: options,
     });
   }
 
   static async getServices(
     context: string,
-    namespace: string
+    namespace: string,
+    options?: RequestOptions
   ): Promise<V1Service[]> {
     return invoke("list_services", {
       context: context,
       namespace: namespace,
+      options: options,
     });
   }
 
@@ -257,33 +473,39 @@ export class Kubernetes {
 
   static async getIngresses(
     context: string,
-    namespace: string
+    namespace: string,
+    options?: RequestOptions
   ): Promise<V1Ingress[]> {
     return invoke("list_ingresses", {
       context: context,
       namespace: namespace,
+      options: options,
     });
   }
 
   static async getPersistentVolumeClaims(
     context: string,
-    namespace: string
+    namespace: string,
+    options?: RequestOptions
   ): Promise<V1PersistentVolumeClaim[]> {
     return invoke("list_persistentvolumeclaims", {
       context: context,
       namespace: namespace,
+      options: options,
     });
   }
 
   static async triggerCronJob(
     context: string,
     namespace: string,
-    name: string
+    name: string,
+    options?: RequestOptions
   ): Promise<boolean> {
     return invoke("trigger_cronjob", {
       context: context,
       namespace: namespace,
       name: name,
+      options: options,
     });
   }
 }